@@ -0,0 +1,126 @@
+//! Times parsing and both parts of every day, on each example and on the full input.
+//!
+//! `cargo bench -p aoc` runs everything, `cargo bench -p aoc -- day_05` a single day. To flag regressions save a
+//! baseline with `cargo bench -p aoc -- --save-baseline before` and compare later runs with `-- --baseline before`.
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::Duration,
+};
+
+use common::{Part, Solver};
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+
+/// Parts that take too long, or all the memory, to be timed repeatedly: day, input file and part.
+const TOO_SLOW: [(u8, &str, Part); 3] = [
+    (18, "example", Part::Two),
+    (18, "input", Part::Two),
+    (23, "input", Part::Two),
+];
+
+/// Files in a day's `src` directory to time it on, examples first.
+fn inputs(dir: &Path) -> Vec<String> {
+    let mut examples = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| name.starts_with("example"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    examples.sort();
+
+    if dir.join("input").exists() {
+        examples.push("input".to_string());
+    }
+    examples
+}
+
+/// Whether `part` gives an answer for `data`. Unsolved parts and parts that panic, e.g. on an example meant for the
+/// other part, aren't worth timing.
+fn answers<S: Solver>(data: &S::Data, part: Part) -> bool {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| solve_part::<S>(data, part)));
+    panic::set_hook(hook);
+
+    matches!(answer, Ok(Some(_)))
+}
+
+fn solve_part<S: Solver>(data: &S::Data, part: Part) -> Option<String> {
+    match part {
+        Part::One => S::part_one(data),
+        Part::Two => S::part_two(data),
+    }
+}
+
+fn bench_input<S: Solver>(group: &mut BenchmarkGroup<'_, WallTime>, dir: &Path, file: &str) {
+    let Ok(text) = fs::read_to_string(dir.join(file)) else {
+        return;
+    };
+    let Ok(data) = S::parse(&text) else {
+        eprintln!("Skipping day {:02} on {}, it can't be parsed", S::DAY, file);
+        return;
+    };
+
+    group.bench_function(format!("parse/{}", file), |b| {
+        b.iter(|| S::parse(black_box(&text)))
+    });
+
+    for part in Part::BOTH {
+        if TOO_SLOW.contains(&(S::DAY, file, part)) {
+            eprintln!(
+                "Skipping day {:02} part {} on {}, it's too slow",
+                S::DAY,
+                part,
+                file
+            );
+            continue;
+        }
+        if !answers::<S>(&data, part) {
+            continue;
+        }
+
+        group.bench_function(format!("part_{}/{}", part, file), |b| {
+            b.iter(|| solve_part::<S>(black_box(&data), part))
+        });
+    }
+}
+
+fn bench_day<S: Solver>(c: &mut Criterion, dir: &Path) {
+    let mut group = c.benchmark_group(format!("day_{:02}", S::DAY));
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500));
+
+    for file in inputs(dir) {
+        bench_input::<S>(&mut group, dir, &file);
+    }
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($krate:ident),*) => {
+        fn days(c: &mut Criterion) {
+            $(
+                bench_day::<$krate::Solution>(
+                    c,
+                    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($krate), "/src")),
+                );
+            )*
+        }
+    };
+}
+
+bench_days!(
+    aoc_01, aoc_02, aoc_03, aoc_04, aoc_05, aoc_06, aoc_07, aoc_08, aoc_09, aoc_10, aoc_11, aoc_12,
+    aoc_13, aoc_14, aoc_15, aoc_16, aoc_17, aoc_18, aoc_19, aoc_20, aoc_21, aoc_22, aoc_23, aoc_24
+);
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,411 @@
+mod verify;
+
+use std::{
+    panic,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use common::{
+    format_answer, init_logging, input::Input, is_verbose_flag, parse::ParseError, solve, Part,
+    Solver,
+};
+use verify::{verify, verify_table, Verdict};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part 1|2] [--input <file, - for stdin>] [--verbose]
+    aoc run --all [--verbose]
+    aoc verify [<day>] [--verbose]
+
+Log levels and days can also be picked with RUST_LOG, e.g. RUST_LOG=aoc_20=trace";
+
+/// Parts that take too long, or all the memory, on the puzzle input for `run --all` to wait for: day and part.
+const TOO_SLOW: [(u8, Part); 2] = [(18, Part::Two), (23, Part::Two)];
+
+/// A day the runner knows how to solve.
+struct Day {
+    number: u8,
+    /// The day's `src` directory, with its puzzle input, examples and answers.
+    src: PathBuf,
+    solve: fn(&str, Part) -> Result<Option<String>, ParseError>,
+}
+
+macro_rules! day {
+    ($krate:ident) => {
+        Day {
+            number: <$krate::Solution as Solver>::DAY,
+            src: PathBuf::from(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../",
+                stringify!($krate),
+                "/src"
+            )),
+            solve: solve::<$krate::Solution>,
+        }
+    };
+}
+
+fn days() -> Vec<Day> {
+    vec![
+        day!(aoc_01),
+        day!(aoc_02),
+        day!(aoc_03),
+        day!(aoc_04),
+        day!(aoc_05),
+        day!(aoc_06),
+        day!(aoc_07),
+        day!(aoc_08),
+        day!(aoc_09),
+        day!(aoc_10),
+        day!(aoc_11),
+        day!(aoc_12),
+        day!(aoc_13),
+        day!(aoc_14),
+        day!(aoc_15),
+        day!(aoc_16),
+        day!(aoc_17),
+        day!(aoc_18),
+        day!(aoc_19),
+        day!(aoc_20),
+        day!(aoc_21),
+        day!(aoc_22),
+        day!(aoc_23),
+        day!(aoc_24),
+    ]
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run {
+        day: u8,
+        part: Option<Part>,
+        input: Option<Input>,
+    },
+    RunAll,
+    Verify {
+        day: Option<u8>,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(|a| a.as_str());
+    match args.next() {
+        Some("run") => parse_run_args(args),
+        Some("verify") => match (args.next(), args.next()) {
+            (None, _) => Ok(Command::Verify { day: None }),
+            (Some(day), None) if !day.starts_with("--") => Ok(Command::Verify {
+                day: Some(parse_day(day)?),
+            }),
+            _ => Err(USAGE.to_string()),
+        },
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_run_args<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut all = false;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => all = true,
+            "--part" => {
+                let value = args.next().ok_or("Missing the part after --part")?;
+                part = Some(parse_part(value)?);
+            }
+            "--input" => {
+                let value = args.next().ok_or("Missing the file after --input")?;
+                input = Some(Input::from_arg(value));
+            }
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(arg)?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    match (all, day) {
+        (false, Some(day)) => Ok(Command::Run { day, part, input }),
+        (true, None) if part.is_none() && input.is_none() => Ok(Command::RunAll),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Not a day of Advent of Code: {}", day)),
+    }
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    match part {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Part must be 1 or 2, not: {}", part)),
+    }
+}
+
+fn main() -> ExitCode {
+    let (verbose, args): (Vec<_>, Vec<_>) = std::env::args()
+        .skip(1)
+        .partition(|arg| is_verbose_flag(arg));
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    init_logging(!verbose.is_empty());
+
+    let days = days();
+    match command {
+        Command::Run { day, part, input } => {
+            let Some(day) = days.iter().find(|d| d.number == day) else {
+                eprintln!("Day {} isn't solved yet.", day);
+                return ExitCode::FAILURE;
+            };
+
+            let input = input.unwrap_or_else(|| Input::File(day.src.join("input")));
+            let text = match input.read() {
+                Ok(text) => text,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+
+            for part in parts {
+                match (day.solve)(&text, part) {
+                    Ok(answer) => {
+                        println!("{}", format_answer(day.number, part, answer.as_deref()))
+                    }
+                    Err(error) => {
+                        eprintln!("Can't parse {}, {}", input, error);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+        Command::RunAll => {
+            let rows = days.iter().map(run_day).collect::<Vec<_>>();
+            println!("{}", summary_table(&rows));
+        }
+        Command::Verify { day } => {
+            let days = days
+                .iter()
+                .filter(|d| day.is_none_or(|day| d.number == day))
+                .collect::<Vec<_>>();
+            if days.is_empty() {
+                eprintln!("Day {} isn't solved yet.", day.unwrap_or_default());
+                return ExitCode::FAILURE;
+            }
+
+            let checks = days.into_iter().flat_map(verify).collect::<Vec<_>>();
+            println!("{}", verify_table(&checks));
+
+            let count = |verdict: fn(&Verdict) -> bool| {
+                checks.iter().filter(|c| verdict(&c.result)).count()
+            };
+            let failed = count(|v| matches!(v, Verdict::Fail(_)));
+            println!(
+                "\n{} passed, {} failed, {} missing",
+                count(|v| *v == Verdict::Pass),
+                failed,
+                count(|v| *v == Verdict::Missing)
+            );
+
+            if failed > 0 {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// One line of the `run --all` summary.
+struct Row {
+    day: u8,
+    answers: [String; 2],
+    time: Duration,
+}
+
+/// Solve both parts of a day, a missing or malformed input or a panicking solver is reported instead of ending the
+/// run. Parts in [`TOO_SLOW`] are skipped.
+fn run_day(day: &Day) -> Row {
+    let start = Instant::now();
+
+    let input = Input::File(day.src.join("input"));
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("{}", error);
+            return Row {
+                day: day.number,
+                answers: Part::BOTH.map(|_| "no input".to_string()),
+                time: start.elapsed(),
+            };
+        }
+    };
+
+    let mut parse_error_reported = false;
+    let answers = Part::BOTH.map(|part| {
+        if TOO_SLOW.contains(&(day.number, part)) {
+            return "too slow".to_string();
+        }
+
+        let answer = panic::catch_unwind(|| (day.solve)(&text, part));
+        match answer {
+            Ok(Ok(Some(answer))) => answer,
+            Ok(Ok(None)) => "unsolved".to_string(),
+            Ok(Err(error)) => {
+                if !parse_error_reported {
+                    eprintln!("Can't parse {}, {}", input, error);
+                    parse_error_reported = true;
+                }
+                "malformed input".to_string()
+            }
+            Err(_) => "panicked".to_string(),
+        }
+    });
+
+    Row {
+        day: day.number,
+        answers,
+        time: start.elapsed(),
+    }
+}
+
+fn summary_table(rows: &[Row]) -> String {
+    let header = ["Day", "Part one", "Part two", "Time"];
+
+    let mut lines = vec![header.map(|h| h.to_string())];
+    for row in rows {
+        lines.push([
+            format!("{:02}", row.day),
+            row.answers[0].clone(),
+            row.answers[1].clone(),
+            format!("{:.1?}", row.time),
+        ]);
+    }
+
+    format_table(&lines)
+}
+
+/// Align `lines` in columns, the first line is the header.
+fn format_table<const N: usize>(lines: &[[String; N]]) -> String {
+    let mut widths = [0; N];
+    for line in lines.iter() {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = std::cmp::max(*width, cell.chars().count());
+        }
+    }
+
+    let mut table = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let cells = line
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell))
+            .collect::<Vec<_>>();
+        table.push(cells.join(" | ").trim_end().to_string());
+
+        if i == 0 {
+            let rule = widths.map(|width| "-".repeat(width));
+            table.push(rule.join("-+-"));
+        }
+    }
+
+    table.join("\n")
+}
+
+#[test]
+fn test_parse_args() {
+    let args = |s: &str| s.split(' ').map(|a| a.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(parse_args(&args("run --all")), Ok(Command::RunAll));
+    assert_eq!(
+        parse_args(&args("run 5")),
+        Ok(Command::Run {
+            day: 5,
+            part: None,
+            input: None
+        })
+    );
+    assert_eq!(
+        parse_args(&args("run 12 --part 2")),
+        Ok(Command::Run {
+            day: 12,
+            part: Some(Part::Two),
+            input: None
+        })
+    );
+    assert_eq!(
+        parse_args(&args("run --input other/input 8")),
+        Ok(Command::Run {
+            day: 8,
+            part: None,
+            input: Some(Input::File(PathBuf::from("other/input")))
+        })
+    );
+    assert_eq!(
+        parse_args(&args("run 8 --part 1 --input -")),
+        Ok(Command::Run {
+            day: 8,
+            part: Some(Part::One),
+            input: Some(Input::Stdin)
+        })
+    );
+
+    assert_eq!(
+        parse_args(&args("verify")),
+        Ok(Command::Verify { day: None })
+    );
+    assert_eq!(
+        parse_args(&args("verify 17")),
+        Ok(Command::Verify { day: Some(17) })
+    );
+
+    assert!(parse_args(&args("run")).is_err());
+    assert!(parse_args(&args("verify 17 18")).is_err());
+    assert!(parse_args(&args("verify --all")).is_err());
+    assert!(parse_args(&args("run 8 --input")).is_err());
+    assert!(parse_args(&args("run --all --input -")).is_err());
+    assert!(parse_args(&args("run 5 --all")).is_err());
+    assert!(parse_args(&args("run 26")).is_err());
+    assert!(parse_args(&args("run 5 --part 3")).is_err());
+    assert!(parse_args(&args("solve 5")).is_err());
+}
+
+#[test]
+fn test_summary_table() {
+    let rows = [
+        Row {
+            day: 4,
+            answers: ["13".to_string(), "30".to_string()],
+            time: Duration::from_millis(2),
+        },
+        Row {
+            day: 12,
+            answers: ["21".to_string(), "unsolved".to_string()],
+            time: Duration::from_micros(150),
+        },
+    ];
+
+    let expected = "\
+Day | Part one | Part two | Time
+----+----------+----------+--------
+04  | 13       | 30       | 2.0ms
+12  | 21       | unsolved | 150.0µs";
+
+    assert_eq!(summary_table(&rows), expected);
+}
@@ -0,0 +1,174 @@
+use std::{collections::HashMap, fmt::Display, panic};
+
+use common::{
+    answers::{expected, read_answers, Answer},
+    input::Input,
+    Part,
+};
+
+use crate::{format_table, Day};
+
+/// How an answer compares to the known correct one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    /// There's no known answer to compare with.
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("pass"),
+            Verdict::Fail(reason) => write!(f, "FAIL, {}", reason),
+            Verdict::Missing => f.write_str("missing"),
+        }
+    }
+}
+
+/// One part of a day checked on one of its inputs.
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub result: Verdict,
+}
+
+/// Check every answer in the day's `answers` file, and both parts of the puzzle input even when their answers aren't
+/// known.
+pub fn verify(day: &Day) -> Vec<Check> {
+    let answers = read_day_answers(day);
+
+    let mut cases = answers
+        .iter()
+        .map(|a| (a.input.clone(), a.part))
+        .collect::<Vec<_>>();
+    for part in Part::BOTH {
+        if expected(&answers, "input", part).is_none() {
+            cases.push(("input".to_string(), part));
+        }
+    }
+
+    let mut texts = HashMap::new();
+    cases
+        .into_iter()
+        .map(|(input, part)| {
+            let result = match expected(&answers, &input, part) {
+                Some(answer) => {
+                    let text = texts
+                        .entry(input.clone())
+                        .or_insert_with(|| Input::File(day.src.join(&input)).read());
+                    check(day, text, part, answer)
+                }
+                None => Verdict::Missing,
+            };
+
+            Check {
+                day: day.number,
+                input,
+                part,
+                result,
+            }
+        })
+        .collect()
+}
+
+/// Known answers of the day, none when it has no answers file.
+fn read_day_answers(day: &Day) -> Vec<Answer> {
+    let path = day.src.join("answers");
+    if !path.exists() {
+        return Vec::new();
+    }
+
+    let input = Input::File(path);
+    let answers = input
+        .read()
+        .map_err(|e| e.to_string())
+        .and_then(|text| read_answers(&text).map_err(|e| format!("Can't parse {}, {}", input, e)));
+
+    answers.unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        Vec::new()
+    })
+}
+
+fn check<E: Display>(day: &Day, text: &Result<String, E>, part: Part, answer: &str) -> Verdict {
+    let text = match text {
+        Ok(text) => text,
+        Err(error) => return Verdict::Fail(error.to_string()),
+    };
+
+    match panic::catch_unwind(|| (day.solve)(text, part)) {
+        Ok(Ok(Some(found))) if found == answer => Verdict::Pass,
+        Ok(Ok(Some(found))) => Verdict::Fail(format!("got {}, expected {}", found, answer)),
+        Ok(Ok(None)) => Verdict::Fail(format!("unsolved, expected {}", answer)),
+        Ok(Err(error)) => Verdict::Fail(format!("malformed input, {}", error)),
+        Err(_) => Verdict::Fail("panicked".to_string()),
+    }
+}
+
+pub fn verify_table(checks: &[Check]) -> String {
+    let header = ["Day", "Input", "Part", "Result"];
+
+    let mut lines = vec![header.map(|h| h.to_string())];
+    for check in checks {
+        lines.push([
+            format!("{:02}", check.day),
+            check.input.clone(),
+            check.part.to_string(),
+            check.result.to_string(),
+        ]);
+    }
+
+    format_table(&lines)
+}
+
+#[test]
+fn test_verify() {
+    let day = crate::days().into_iter().find(|d| d.number == 9).unwrap();
+    let checks = verify(&day);
+
+    let example = checks
+        .iter()
+        .filter(|c| c.input == "example")
+        .map(|c| (c.part, &c.result))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        example,
+        [(Part::One, &Verdict::Pass), (Part::Two, &Verdict::Pass)]
+    );
+
+    let text = Ok::<_, String>("0 3 6 9 12 15\n".to_string());
+    assert_eq!(check(&day, &text, Part::One, "18"), Verdict::Pass);
+    assert_eq!(
+        check(&day, &text, Part::One, "19"),
+        Verdict::Fail("got 18, expected 19".to_string())
+    );
+}
+
+#[test]
+fn test_verify_table() {
+    let checks = [
+        Check {
+            day: 7,
+            input: "example".to_string(),
+            part: Part::Two,
+            result: Verdict::Pass,
+        },
+        Check {
+            day: 7,
+            input: "input".to_string(),
+            part: Part::One,
+            result: Verdict::Missing,
+        },
+    ];
+
+    let expected = "\
+Day | Input   | Part | Result
+----+---------+------+--------
+07  | example | two  | pass
+07  | input   | one  | missing";
+
+    assert_eq!(verify_table(&checks), expected);
+}
@@ -0,0 +1,104 @@
+//! Day 1: Trebuchet?!, calibration values hidden in lines of text.
+
+use common::{parse::ParseError, Solver};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 1;
+    type Data = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// Spelled out digits, `one` is 1.
+const NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digits of a line of the calibration document, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// Only those written as digits.
+    pub digits: Vec<u32>,
+    /// Those written as digits or spelled out, e.g. `eight`. Names can overlap, `twone` is 2 and 1.
+    pub spelled: Vec<u32>,
+}
+
+/// Every line of the document with its digits.
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    let lines = input
+        .lines()
+        .map(|line| {
+            let mut digits = Vec::new();
+            let mut spelled = Vec::new();
+            for (i, char) in line.char_indices() {
+                if let Some(digit) = char.to_digit(10) {
+                    digits.push(digit);
+                    spelled.push(digit);
+                } else if let Some(n) = NAMES.iter().position(|name| line[i..].starts_with(name)) {
+                    spelled.push(n as u32 + 1);
+                }
+            }
+            trace!("{}: {:?}, {:?}", line, digits, spelled);
+
+            Line { digits, spelled }
+        })
+        .collect();
+
+    Ok(lines)
+}
+
+/// The first and last digit as a two digit number, 0 without digits.
+pub fn calibration_value(digits: &[u32]) -> u32 {
+    match (digits.first(), digits.last()) {
+        (Some(first), Some(last)) => first * 10 + last,
+        _ => 0,
+    }
+}
+
+/// Sum of the numbers made of the first and last digit of every line.
+pub fn part_one(lines: &[Line]) -> u32 {
+    lines
+        .iter()
+        .map(|line| calibration_value(&line.digits))
+        .sum()
+}
+
+/// Like [`part_one`], but digits can also be spelled out, e.g. `eight`.
+pub fn part_two(lines: &[Line]) -> u32 {
+    lines
+        .iter()
+        .map(|line| calibration_value(&line.spelled))
+        .sum()
+}
+
+#[test]
+fn test() {
+    let example = parse("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n").unwrap();
+    assert_eq!(part_one(&example), 142);
+
+    let example = parse(
+        "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n",
+    )
+    .unwrap();
+    assert_eq!(part_two(&example), 281);
+    assert_eq!(example[1].digits, []);
+    assert_eq!(example[1].spelled, [8, 2, 3]);
+
+    let input = parse(include_str!("input")).unwrap();
+    assert_eq!(part_one(&input), 55971);
+    assert_eq!(part_two(&input), 54719);
+}
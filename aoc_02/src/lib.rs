@@ -0,0 +1,182 @@
+//! Day 2: Cube Conundrum, games of drawing coloured cubes from a bag.
+
+use std::str::FromStr;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 2;
+    type Data = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// A handful of cubes, or the contents of the bag.
+#[derive(PartialEq, Debug)]
+pub struct Cubes {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl std::cmp::PartialOrd for Cubes {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.red <= other.red && self.green <= other.green && self.blue <= other.blue {
+            return Some(std::cmp::Ordering::Equal);
+        }
+        if self.red < other.red && self.green < other.green && self.blue < other.blue {
+            return Some(std::cmp::Ordering::Less);
+        }
+        Some(std::cmp::Ordering::Greater)
+    }
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    /// A reveal like `3 blue, 4 red`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for data in s.split(',').map(|x| x.trim()) {
+            let (num, color) = parse::split_once(s, data, " ")?;
+            let n = parse::number::<u32>(s, num)?;
+
+            match color {
+                "red" => red = n,
+                "green" => green = n,
+                "blue" => blue = n,
+                _ => return Err(ParseError::new(s, color, "one of `red`, `green` or `blue`")),
+            }
+        }
+
+        Ok(Cubes { red, green, blue })
+    }
+}
+
+#[test]
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+fn compare() {
+    let a = Cubes {
+        red: 2,
+        green: 3,
+        blue: 4,
+    };
+    let b = Cubes {
+        red: 1,
+        green: 3,
+        blue: 4,
+    };
+    assert!(!(b < a));
+    assert!(b <= a);
+    assert!(a != b);
+}
+
+/// A line like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+pub struct Game {
+    pub id: u32,
+    /// Handfuls of cubes shown during the game.
+    pub reveals: Vec<Cubes>,
+}
+
+fn read_game(line: &str) -> Result<Game, ParseError> {
+    let game = parse::strip_prefix(line, line, "Game ")?;
+    let (id, data) = parse::split_once(line, game, ":")?;
+    let id = parse::number(line, id)?;
+
+    trace!(" == {} == ", line);
+
+    let mut reveals = Vec::new();
+    for reveal in data.split(';') {
+        let cubes = reveal
+            .parse::<Cubes>()
+            .map_err(|e| e.within(line, reveal))?;
+        trace!("Input: {}, data: {:?}", reveal, cubes);
+        reveals.push(cubes);
+    }
+
+    Ok(Game { id, reveals })
+}
+
+/// One game per line.
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .map(|line| read_game(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Sum of possible games' ids.
+pub fn part_one(games: &[Game]) -> u32 {
+    let limit = Cubes {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    let mut id_sum: u32 = 0;
+    for game in games {
+        let ok = !game.reveals.iter().any(|cubes| *cubes > limit);
+
+        if ok {
+            trace!("Id: {} is good!", game.id);
+            id_sum += game.id;
+        }
+    }
+
+    id_sum
+}
+
+/// Sum of the powers of the fewest cubes each game could have been played with.
+pub fn part_two(games: &[Game]) -> u32 {
+    let mut power_sum: u32 = 0;
+
+    for game in games {
+        let mut max_r = 0;
+        let mut max_g = 0;
+        let mut max_b = 0;
+
+        for cubes in game.reveals.iter() {
+            max_r = std::cmp::max(max_r, cubes.red);
+            max_g = std::cmp::max(max_g, cubes.green);
+            max_b = std::cmp::max(max_b, cubes.blue);
+        }
+
+        let power = max_b * max_r * max_g;
+        power_sum += power;
+    }
+
+    power_sum
+}
+
+#[test]
+fn test_read_error() {
+    let input = "Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 purple\n";
+    let error = parse(input).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 18: expected one of `red`, `green` or `blue`, found `purple`"
+    );
+
+    let error = parse("Game x: 1 red").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 6));
+}
@@ -0,0 +1,213 @@
+//! Day 3: Gear Ratios, part numbers in an engine schematic.
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 3;
+    type Data = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        part_two(data).map(|sum| sum.to_string())
+    }
+}
+
+/// A number in the schematic, on `row` from column `start` up to `end`, counted in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl PartNumber {
+    /// Whether the tile at `row`, `col` touches the number, diagonals included.
+    pub fn is_adjacent(&self, row: usize, col: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && col + 1 >= self.start && col <= self.end
+    }
+}
+
+/// Anything in the schematic that's neither a digit nor a `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// The engine schematic, its numbers and symbols row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<PartNumber>,
+    pub symbols: Vec<Symbol>,
+}
+
+/// A character per tile, numbers are runs of digits.
+pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let mut numbers = Vec::new();
+    let mut symbols = Vec::new();
+
+    for (row, line) in input.lines().enumerate() {
+        trace!("{}", line);
+
+        // Start of the number being read, in bytes and in characters.
+        let mut number: Option<(usize, usize)> = None;
+        for (col, (i, char)) in line.char_indices().chain([(line.len(), '.')]).enumerate() {
+            if char.is_ascii_digit() {
+                number.get_or_insert((i, col));
+                continue;
+            }
+
+            if let Some((byte_start, start)) = number.take() {
+                numbers.push(PartNumber {
+                    value: parse::number(input, &line[byte_start..i])?,
+                    row,
+                    start,
+                    end: col,
+                });
+            }
+            if char != '.' {
+                symbols.push(Symbol {
+                    symbol: char,
+                    row,
+                    col,
+                });
+            }
+        }
+    }
+    trace!("{:?}", numbers);
+
+    Ok(Schematic { numbers, symbols })
+}
+
+/// Sum of the numbers next to a symbol.
+pub fn part_one(schematic: &Schematic) -> u64 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| {
+            schematic
+                .symbols
+                .iter()
+                .any(|symbol| number.is_adjacent(symbol.row, symbol.col))
+        })
+        .map(|number| number.value as u64)
+        .sum()
+}
+
+/// Sum of the gear ratios, the products of the two numbers next to a `*`. `None` if it doesn't fit in 64 bits.
+pub fn part_two(schematic: &Schematic) -> Option<u64> {
+    // Add up all the mulpiples of number around a gear. (Only when there are exactly two numbers around a gear)
+    // 467..114..
+    // ...*......
+    // ..35..633.
+    // ......#...
+    // 617*......
+    // .....+.58.
+    // ..592.....
+    // ......755.
+    // ...$.*....
+    // .664.598..
+
+    schematic
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.symbol == '*')
+        .filter_map(|gear| {
+            let adjacent = schematic
+                .numbers
+                .iter()
+                .filter(|number| number.is_adjacent(gear.row, gear.col))
+                .collect::<Vec<_>>();
+
+            match adjacent[..] {
+                [a, b] => Some(a.value as u64 * b.value as u64),
+                _ => None,
+            }
+        })
+        .try_fold(0u64, |sum, ratio| sum.checked_add(ratio))
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+    let example_sum = part_one(&example);
+
+    assert_eq!(example_sum, 4361);
+
+    let input = parse(include_str!("input")).unwrap();
+    let input_sum = part_one(&input);
+    assert_eq!(input_sum, 525181);
+
+    // part_two example should be: 467835
+    let example_2 = part_two(&example);
+    assert_eq!(example_2, Some(467835));
+}
+
+#[test]
+fn test_parse() {
+    let schematic = parse("....123\n...456.\né1*\n").unwrap();
+
+    assert_eq!(
+        schematic.numbers,
+        [
+            PartNumber {
+                value: 123,
+                row: 0,
+                start: 4,
+                end: 7
+            },
+            PartNumber {
+                value: 456,
+                row: 1,
+                start: 3,
+                end: 6
+            },
+            PartNumber {
+                value: 1,
+                row: 2,
+                start: 1,
+                end: 2
+            },
+        ]
+    );
+    assert_eq!(
+        schematic.symbols,
+        [
+            Symbol {
+                symbol: 'é',
+                row: 2,
+                col: 0
+            },
+            Symbol {
+                symbol: '*',
+                row: 2,
+                col: 2
+            },
+        ]
+    );
+    assert_eq!(part_one(&schematic), 457);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("..1.\n99999999999*\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.found, "99999999999");
+    assert_eq!(error.expected, "a number");
+}
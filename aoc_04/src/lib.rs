@@ -0,0 +1,134 @@
+//! Day 4: Scratchcards, matching winning numbers.
+
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 4;
+    type Data = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// A scratchcard, its winning numbers and the numbers on it.
+pub struct Card {
+    pub winning: Vec<u32>,
+    pub mine: Vec<u32>,
+}
+
+/// A line like `Card 1: 41 48 83 | 83 86  6`.
+fn parse_card(line: &str) -> Result<Card, ParseError> {
+    let (_, numbers) = parse::split_once(line, line, ":")?;
+    let (winning_numbers, my_numbers) = parse::split_once(line, numbers, "|")?;
+
+    Ok(Card {
+        winning: parse::numbers(line, winning_numbers)?,
+        mine: parse::numbers(line, my_numbers)?,
+    })
+}
+
+/// One card per line, in order.
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input
+        .lines()
+        .filter(|line| line.contains("Card"))
+        .map(|line| parse_card(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// How many of the card's numbers are winning.
+pub fn count_matches(card: &Card) -> usize {
+    card.mine
+        .iter()
+        .filter(|n| card.winning.contains(n))
+        .count()
+}
+
+/// Total points, a card is worth 1 point for its first match, doubled for every further one.
+pub fn part_one(cards: &[Card]) -> i32 {
+    let mut points = 0;
+
+    for card in cards {
+        let count = count_matches(card);
+        if count > 0 {
+            let point = 2_i32.pow((count - 1).try_into().unwrap());
+            points += point;
+        }
+    }
+
+    points
+}
+
+/// Number of cards in the end, when every match wins a copy of one of the following cards.
+pub fn part_two(cards: &[Card]) -> usize {
+    let mut card_count = 0;
+    let mut extras = HashMap::new();
+
+    for (i, card) in cards.iter().enumerate() {
+        card_count += 1;
+
+        let count = count_matches(card);
+
+        let current_id = i + 1;
+        let mut instances_of_current = 1;
+        if let Some(value) = extras.get(&current_id) {
+            instances_of_current += value
+        };
+
+        trace!(
+            "Card [{}] has {} instances.",
+            current_id,
+            instances_of_current
+        );
+
+        if count > 0 {
+            let new_cards = current_id + 1..=current_id + count;
+
+            for id in new_cards {
+                match extras.get(&id) {
+                    Some(value) => extras.insert(id, value + instances_of_current),
+                    None => extras.insert(id, instances_of_current),
+                };
+
+                card_count += instances_of_current;
+            }
+        }
+    }
+
+    card_count
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+    let example_one = part_one(&example);
+    assert_eq!(example_one, 13);
+
+    let input = parse(include_str!("input")).unwrap();
+    let input_one = part_one(&input);
+    assert_eq!(input_one, 25231);
+
+    let example_two = part_two(&example);
+    assert_eq!(example_two, 30);
+
+    let input_two = part_two(&input);
+    assert_eq!(input_two, 9721255);
+}
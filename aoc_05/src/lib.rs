@@ -0,0 +1,553 @@
+//! Day 5: If You Give A Seed A Fertilizer, chained almanac maps from seeds to locations.
+
+use std::{collections::HashMap, ops::Range, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::{debug, trace};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 5;
+    type Data = Data;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        part_one(data).map(|location| location.to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        part_two(data).ok()?.map(|location| location.to_string())
+    }
+}
+
+/// Numbers from `source_start` on, `range_length` of them, are moved by `offset`.
+#[derive(Debug)]
+pub struct MapEntry {
+    pub source_start: i64,
+    pub range_length: i64,
+    pub offset: i64,
+}
+
+impl FromStr for MapEntry {
+    type Err = ParseError;
+
+    /// Destination start, source start and length, like `50 98 2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = natural_numbers(s, s)?;
+        let [destination_start, source_start, range_length] = numbers[..] else {
+            return Err(ParseError::new(s, s, "three numbers"));
+        };
+
+        let out_of_range = || ParseError::new(s, s, "ranges that fit in 64 bits");
+        source_start
+            .checked_add(range_length)
+            .ok_or_else(out_of_range)?;
+        destination_start
+            .checked_add(range_length)
+            .ok_or_else(out_of_range)?;
+
+        Ok(MapEntry {
+            offset: destination_start
+                .checked_sub(source_start)
+                .ok_or_else(out_of_range)?,
+            source_start,
+            range_length,
+        })
+    }
+}
+
+/// Ranges a map moves, with their offset, and ranges it leaves alone.
+type Split = (Vec<(Range<i64>, i64)>, Vec<Range<i64>>);
+
+/// Converts numbers of one category, e.g. seeds, to the next one, e.g. soil.
+#[derive(Debug)]
+pub struct Map {
+    pub from: String,
+    pub to: String,
+    pub entries: Vec<MapEntry>,
+}
+
+impl Map {
+    /// Numbers no entry covers stay the same.
+    pub fn convert(&self, num: i64) -> i64 {
+        let mut ret = num;
+        for entry in &self.entries {
+            if num >= entry.source_start && num < entry.source_start + entry.range_length {
+                ret = num + entry.offset;
+                break;
+            }
+        }
+
+        ret
+    }
+
+    /// [`Map::convert`] for whole ranges, a range covering parts of several entries is split along their edges.
+    pub fn convert_ranges(&self, ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
+        let mut converted = Vec::new();
+        for range in ranges {
+            let (moved, kept) = self.split(range);
+            converted.extend(moved.into_iter().map(|(r, offset)| shift(r, offset)));
+            converted.extend(kept);
+        }
+
+        converted
+    }
+
+    /// The parts of `range` each entry moves, with their offset, and the parts no entry covers.
+    fn split(&self, range: Range<i64>) -> Split {
+        let mut unmapped = vec![range];
+        let mut mapped = Vec::new();
+
+        for entry in &self.entries {
+            let source = entry.source();
+
+            let mut rest = Vec::new();
+            for range in unmapped {
+                let overlap = intersect(&range, &source);
+                if overlap.is_empty() {
+                    rest.push(range);
+                    continue;
+                }
+
+                rest.push(range.start..overlap.start);
+                rest.push(overlap.end..range.end);
+                mapped.push((overlap, entry.offset));
+            }
+            unmapped = rest.into_iter().filter(|r| !r.is_empty()).collect();
+        }
+
+        (mapped, unmapped)
+    }
+
+    /// The whole map as disjoint pieces, like [`Map::split`] over every number.
+    fn pieces(&self) -> Split {
+        self.split(i64::MIN..i64::MAX)
+    }
+
+    /// A single map doing this one and then `next`, from this map's category to the one `next` converts to.
+    pub fn then(&self, next: &Map) -> Map {
+        let (moved, kept) = self.pieces();
+
+        let mut entries = Vec::new();
+        for (range, offset) in moved {
+            let (next_moved, next_kept) = next.split(shift(range, offset));
+            entries.extend(
+                next_moved
+                    .into_iter()
+                    .map(|(r, next_offset)| (shift(r, -offset), offset + next_offset)),
+            );
+            entries.extend(next_kept.into_iter().map(|r| (shift(r, -offset), offset)));
+        }
+        for range in kept {
+            entries.extend(next.split(range).0);
+        }
+
+        Map {
+            from: self.from.clone(),
+            to: next.to.clone(),
+            entries: to_entries(entries),
+        }
+    }
+
+    /// Every number that converts to one in `range`, as disjoint ranges from lowest to highest.
+    pub fn preimage(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        let (moved, kept) = self.pieces();
+
+        let mut found = moved
+            .into_iter()
+            .map(|(r, offset)| shift(intersect(&shift(r, offset), &range), -offset))
+            .chain(kept.iter().map(|r| intersect(r, &range)))
+            .filter(|r| !r.is_empty())
+            .collect::<Vec<_>>();
+        found.sort_by_key(|r| r.start);
+
+        found
+    }
+
+    /// The map converting back, `None` unless every number converts to a different one.
+    pub fn inverse(&self) -> Option<Map> {
+        let (moved, _) = self.pieces();
+
+        // Numbers no entry covers stay the same, so the entries must move their numbers onto each other.
+        let sources = union(moved.iter().map(|(r, _)| r.clone()).collect())?;
+        let destinations = union(moved.iter().map(|(r, o)| shift(r.clone(), *o)).collect())?;
+        if sources != destinations {
+            return None;
+        }
+
+        Some(Map {
+            from: self.to.clone(),
+            to: self.from.clone(),
+            entries: to_entries(moved.into_iter().map(|(r, o)| (shift(r, o), -o)).collect()),
+        })
+    }
+}
+
+impl MapEntry {
+    /// The numbers the entry moves.
+    pub fn source(&self) -> Range<i64> {
+        self.source_start..self.source_start + self.range_length
+    }
+
+    /// Where the entry moves them to.
+    pub fn destination(&self) -> Range<i64> {
+        shift(self.source(), self.offset)
+    }
+}
+
+fn shift(range: Range<i64>, offset: i64) -> Range<i64> {
+    range.start + offset..range.end + offset
+}
+
+fn intersect(a: &Range<i64>, b: &Range<i64>) -> Range<i64> {
+    a.start.max(b.start)..a.end.min(b.end)
+}
+
+/// The numbers in any of `ranges` as few ranges as possible, `None` if some of them overlap.
+fn union(mut ranges: Vec<Range<i64>>) -> Option<Vec<Range<i64>>> {
+    ranges.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<Range<i64>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start < last.end => return None,
+            Some(last) if range.start == last.end => last.end = range.end,
+            _ => merged.push(range),
+        }
+    }
+
+    Some(merged)
+}
+
+/// Entries for pieces moved by their offset, pieces that don't move need none.
+fn to_entries(pieces: Vec<(Range<i64>, i64)>) -> Vec<MapEntry> {
+    pieces
+        .into_iter()
+        .filter(|(range, offset)| *offset != 0 && !range.is_empty())
+        .map(|(range, offset)| MapEntry {
+            source_start: range.start,
+            range_length: range.end - range.start,
+            offset,
+        })
+        .collect()
+}
+
+/// There's no chain of maps between the categories.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LookUpError;
+
+/// Convert `input` through the maps from category `from` until category `to`.
+pub fn look_up(
+    from: &str,
+    to: &str,
+    data: &HashMap<String, Map>,
+    input: i64,
+) -> Result<i64, LookUpError> {
+    let mut map = data.get(&from.to_string()).ok_or(LookUpError)?;
+    let mut lookup = map.convert(input);
+
+    loop {
+        if map.to == to {
+            break;
+        }
+
+        map = data.get(&map.to).ok_or(LookUpError)?;
+        lookup = map.convert(lookup);
+    }
+
+    Ok(lookup)
+}
+
+/// Like [`look_up`] for every number in `ranges` at once, the results are ranges in no particular order.
+pub fn look_up_ranges(
+    from: &str,
+    to: &str,
+    data: &HashMap<String, Map>,
+    ranges: Vec<Range<i64>>,
+) -> Result<Vec<Range<i64>>, LookUpError> {
+    let mut map = data.get(from).ok_or(LookUpError)?;
+    let mut ranges = map.convert_ranges(ranges);
+
+    while map.to != to {
+        map = data.get(&map.to).ok_or(LookUpError)?;
+        ranges = map.convert_ranges(ranges);
+    }
+
+    Ok(ranges)
+}
+
+/// The maps from category `from` until category `to` as a single one.
+pub fn compose(from: &str, to: &str, data: &HashMap<String, Map>) -> Result<Map, LookUpError> {
+    let mut map = data.get(from).ok_or(LookUpError)?;
+    let mut composed = map.then(&Map {
+        from: map.to.clone(),
+        to: map.to.clone(),
+        entries: Vec::new(),
+    });
+
+    while map.to != to {
+        map = data.get(&map.to).ok_or(LookUpError)?;
+        composed = composed.then(map);
+    }
+
+    Ok(composed)
+}
+
+/// The almanac.
+pub struct Data {
+    /// By the category they convert from.
+    pub maps: HashMap<String, Map>,
+    pub seeds: Vec<i64>,
+}
+
+impl Data {
+    /// The seeds line read as pairs of range start and length, a range too long to fit ends at the largest number.
+    pub fn seed_ranges(&self) -> Vec<Range<i64>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0].saturating_add(pair[1]))
+            .collect()
+    }
+}
+
+/// Every seed number that ends up at `location`.
+pub fn seeds_at(data: &Data, location: i64) -> Result<Vec<i64>, LookUpError> {
+    let seed_to_location = compose("seed", "location", &data.maps)?;
+    let seeds = seed_to_location.preimage(location..location + 1);
+
+    Ok(seeds.into_iter().flatten().collect())
+}
+
+/// Lowest location of any seed in `seeds`, `None` when they're all empty.
+pub fn lowest_location(data: &Data, seeds: &[Range<i64>]) -> Result<Option<i64>, LookUpError> {
+    let seed_to_location = compose("seed", "location", &data.maps)?;
+    let locations = seed_to_location.convert_ranges(seeds.to_vec());
+    debug!("Seed ranges end up in {} location ranges", locations.len());
+
+    Ok(locations.iter().map(|r| r.start).min())
+}
+
+/// [`parse::numbers`] that can't be negative, which keeps every offset and range end of the maps in range.
+fn natural_numbers(text: &str, found: &str) -> Result<Vec<i64>, ParseError> {
+    found
+        .split_whitespace()
+        .map(|word| match parse::number(text, word)? {
+            n if n < 0 => Err(ParseError::new(text, word, "a number that isn't negative")),
+            n => Ok(n),
+        })
+        .collect()
+}
+
+/// A header like `seed-to-soil map:` followed by a line per entry.
+fn read_map(input: &str, block: &str) -> Result<Map, ParseError> {
+    let mut lines = block.lines();
+    let header = lines.next().unwrap_or_default();
+
+    let name = header
+        .strip_suffix(" map:")
+        .ok_or_else(|| ParseError::new(input, header, "`<from>-to-<to> map:`"))?;
+    let (from, to) = parse::split_once(input, name, "-to-")?;
+    trace!("{}, from: {}, to: {}", name, from, to);
+
+    let entries = lines
+        .map(|line| line.parse().map_err(|e: ParseError| e.within(input, line)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Map {
+        from: from.to_string(),
+        to: to.to_string(),
+        entries,
+    })
+}
+
+/// The seeds on the first line, then the maps separated by empty lines.
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    let mut blocks = input.split("\n\n").filter(|block| !block.trim().is_empty());
+
+    let seeds = blocks.next().unwrap_or_default().trim_end();
+    let seeds = parse::strip_prefix(input, seeds, "seeds:")?;
+    let seeds = natural_numbers(input, seeds)?;
+    debug!("Seeds: {:?}", seeds);
+
+    let mut maps = HashMap::new();
+    for block in blocks {
+        let map = read_map(input, block)?;
+        debug!("Created map: {:?}", map);
+
+        maps.insert(map.from.clone(), map);
+    }
+
+    Ok(Data { maps, seeds })
+}
+
+/// Lowest location of any seed, `None` if no seed can be looked up.
+pub fn part_one(data: &Data) -> Option<i64> {
+    let maps = &data.maps;
+    let seeds = &data.seeds;
+
+    let mut min = None;
+    for seed in seeds {
+        let ans = look_up("seed", "location", maps, seed.to_owned());
+
+        trace!("Ans: {:?}", ans);
+
+        if let Ok(ans) = ans {
+            min = Some(min.map_or(ans, |min| std::cmp::min(min, ans)));
+        }
+    }
+
+    min
+}
+
+/// Lowest location when the seeds line holds pairs of range start and length, `None` when there are no seeds.
+pub fn part_two(data: &Data) -> Result<Option<i64>, LookUpError> {
+    lowest_location(data, &data.seed_ranges())
+}
+
+#[test]
+fn test_part_one() {
+    let data = parse(include_str!("input")).unwrap();
+    let min = part_one(&data);
+
+    assert_eq!(Some(107430936), min);
+}
+
+#[test]
+fn test_example() {
+    let data = parse(include_str!("example")).unwrap();
+    let maps = &data.maps;
+
+    assert_eq!(look_up("seed", "soil", maps, 79).unwrap(), 81);
+    assert_eq!(look_up("seed", "soil", maps, 14).unwrap(), 14);
+    assert_eq!(look_up("seed", "soil", maps, 55).unwrap(), 57);
+    assert_eq!(look_up("seed", "soil", maps, 13).unwrap(), 13);
+
+    // Seed number 79 corresponds to soil number 81.
+    // Seed number 14 corresponds to soil number 14.
+    // Seed number 55 corresponds to soil number 57.
+    // Seed number 13 corresponds to soil number 13.
+
+    assert_eq!(look_up("seed", "fertilizer", maps, 79).unwrap(), 81);
+    assert_eq!(look_up("seed", "fertilizer", maps, 14).unwrap(), 53);
+    assert_eq!(look_up("seed", "fertilizer", maps, 55).unwrap(), 57);
+    assert_eq!(look_up("seed", "fertilizer", maps, 13).unwrap(), 52);
+
+    assert_eq!(look_up("seed", "location", maps, 79).unwrap(), 82);
+    assert_eq!(look_up("seed", "location", maps, 14).unwrap(), 43);
+    assert_eq!(look_up("seed", "location", maps, 55).unwrap(), 86);
+    assert_eq!(look_up("seed", "location", maps, 13).unwrap(), 35);
+
+    // Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82.
+    // Seed 14, soil 14, fertilizer 53, water 49, light 42, temperature 42, humidity 43, location 43.
+    // Seed 55, soil 57, fertilizer 57, water 53, light 46, temperature 82, humidity 82, location 86.
+    // Seed 13, soil 13, fertilizer 52, water 41, light 34, temperature 34, humidity 35, location 35.
+
+    let part1_min = part_one(&data);
+    let part2_min = part_two(&data);
+
+    assert_eq!(part1_min, Some(35));
+    assert_eq!(part2_min, Ok(Some(46)));
+}
+
+#[test]
+fn test_convert_ranges() {
+    let data = parse(include_str!("example")).unwrap();
+    let seed_to_soil = &data.maps["seed"];
+
+    let mut soil = seed_to_soil.convert_ranges(vec![79..93, 45..55, 95..101]);
+    soil.sort_by_key(|r| r.start);
+    assert_eq!(soil, [45..50, 50..52, 52..57, 81..95, 97..100, 100..101]);
+
+    assert!(seed_to_soil.convert_ranges(vec![60..60, 99..99]).is_empty());
+
+    let mut location =
+        look_up_ranges("seed", "location", &data.maps, vec![79..80, 82..83]).unwrap();
+    location.sort_by_key(|r| r.start);
+    assert_eq!(location, [46..47, 82..83]);
+}
+
+#[test]
+fn test_compose() {
+    let data = parse(include_str!("example")).unwrap();
+    let seed_to_location = compose("seed", "location", &data.maps).unwrap();
+    assert_eq!(
+        (seed_to_location.from.as_str(), seed_to_location.to.as_str()),
+        ("seed", "location")
+    );
+
+    for seed in 0..120 {
+        let location = look_up("seed", "location", &data.maps, seed).unwrap();
+        assert_eq!(seed_to_location.convert(seed), location);
+    }
+
+    let location_to_seed = seed_to_location.inverse().unwrap();
+    assert_eq!(location_to_seed.convert(82), 79);
+    assert_eq!(seeds_at(&data, 46).unwrap(), [82]);
+    assert_eq!(lowest_location(&data, &[79..93, 55..68]).unwrap(), Some(46));
+    assert_eq!(lowest_location(&data, &[]).unwrap(), None);
+
+    assert!(compose("location", "seed", &data.maps).is_err());
+}
+
+#[test]
+fn test_preimage() {
+    // Both 0 and 5 convert to 5, nothing converts to 0.
+    let map = Map {
+        from: "a".to_string(),
+        to: "b".to_string(),
+        entries: vec!["5 0 1".parse().unwrap()],
+    };
+
+    assert_eq!(map.preimage(5..6), [0..1, 5..6]);
+    assert!(map.preimage(0..1).is_empty());
+    assert!(map.inverse().is_none());
+}
+
+#[test]
+fn test_read_error() {
+    let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+    let error = parse(input).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 5, column 1: expected three numbers, found `52 50`"
+    );
+
+    let input = "seeds: 79 14\n\nseed-to-soil:\n50 98 2\n";
+    let error = parse(input).err().unwrap();
+    assert_eq!((error.line, error.column), (3, 1));
+
+    let input = "seeds: 1\n\na-to-b map:\n9223372036854775807 -9223372036854775808 1\n";
+    let error = parse(input).err().unwrap();
+    assert_eq!((error.line, error.column), (4, 21));
+    assert_eq!(error.expected, "a number that isn't negative");
+
+    let input = "seeds: 1\n\na-to-b map:\n0 9223372036854775807 1\n";
+    let error = parse(input).err().unwrap();
+    assert_eq!((error.line, error.column), (4, 1));
+    assert_eq!(error.expected, "ranges that fit in 64 bits");
+}
+
+#[test]
+fn test_large_seed_range() {
+    let data =
+        parse("seeds: 5 9223372036854775807 1 1\n\nseed-to-location map:\n0 10 5\n").unwrap();
+    assert_eq!(data.seed_ranges(), [5..i64::MAX, 1..2]);
+    assert_eq!(part_two(&data), Ok(Some(0)));
+}
+
+#[test]
+fn test_missing_map() {
+    let data = parse("seeds: 1 2\n\nseed-to-soil map:\n0 10 5\n").unwrap();
+    assert_eq!(part_one(&data), None);
+    assert_eq!(part_two(&data), Err(LookUpError));
+    assert_eq!(Solution::part_one(&data), None);
+    assert_eq!(Solution::part_two(&data), None);
+}
@@ -0,0 +1,211 @@
+//! Day 6: Wait For It, boat races won by holding the button long enough.
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 6;
+    type Data = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// How long a race lasts and the record distance to beat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u128,
+    pub distance: u128,
+}
+
+/// The race sheet.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sheet {
+    /// A race per column.
+    pub races: Vec<Race>,
+    /// The single race the sheet really describes, once the spaces between the numbers are ignored.
+    pub kerned: Race,
+}
+
+/// A `Time:` line and a `Distance:` line, with a column per race.
+pub fn parse(input: &str) -> Result<Sheet, ParseError> {
+    let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+    let times = parse::strip_prefix(input, lines.next().unwrap_or_default(), "Time:")?;
+    let distances = parse::strip_prefix(input, lines.next().unwrap_or_default(), "Distance:")?;
+    if let Some(line) = lines.next() {
+        return Err(ParseError::new(input, line, "the end of the sheet"));
+    }
+
+    let time_list = parse::numbers(input, times)?;
+    let distance_list = parse::numbers::<u128>(input, distances)?;
+    if distance_list.len() != time_list.len() {
+        return Err(ParseError::new(
+            input,
+            distances,
+            format!("{} distances, one per time", time_list.len()),
+        ));
+    }
+
+    let races = time_list
+        .into_iter()
+        .zip(distance_list)
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
+    let kerned = Race {
+        time: kerned_number(input, times)?,
+        distance: kerned_number(input, distances)?,
+    };
+
+    Ok(Sheet { races, kerned })
+}
+
+/// The digits of `found`, a part of `text`, as a single number.
+fn kerned_number(text: &str, found: &str) -> Result<u128, ParseError> {
+    let digits = found.split_whitespace().collect::<String>();
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(text, found, "a number"))
+}
+
+/// Product of the ways to win each of the races.
+pub fn part_one(sheet: &Sheet) -> u128 {
+    sheet
+        .races
+        .iter()
+        .map(|race| ways_to_beat_record(race.time, race.distance))
+        .product()
+}
+
+/// Ways to win the single race written without the spaces.
+pub fn part_two(sheet: &Sheet) -> u128 {
+    ways_to_beat_record(sheet.kerned.time, sheet.kerned.distance)
+}
+
+/// How many whole milliseconds of holding the button go further than `record_distance`.
+///
+/// Holding for `h` goes `h * (available_time - h)`, so the winning hold times lie strictly between the roots of
+/// `h² - available_time * h + record_distance`, symmetric around half the time. Found with an integer square root,
+/// or a binary search when `available_time²` doesn't fit.
+pub fn ways_to_beat_record(available_time: u128, record_distance: u128) -> u128 {
+    let beats = |hold_time: u128| {
+        hold_time
+            .checked_mul(available_time - hold_time)
+            .is_none_or(|distance| distance > record_distance)
+    };
+
+    let middle = available_time / 2;
+    if !beats(middle) {
+        return 0;
+    }
+
+    let first = match available_time.checked_mul(available_time) {
+        Some(square) => {
+            // Holding for the middle wins, so `4 * record_distance < square`.
+            let root = (square - 4 * record_distance).isqrt();
+            let mut first = (available_time - root) / 2;
+            while first > 0 && beats(first - 1) {
+                first -= 1;
+            }
+            while !beats(first) {
+                first += 1;
+            }
+            first
+        }
+        None => {
+            let (mut low, mut high) = (0, middle);
+            while low < high {
+                let hold_time = low + (high - low) / 2;
+                if beats(hold_time) {
+                    high = hold_time;
+                } else {
+                    low = hold_time + 1;
+                }
+            }
+            low
+        }
+    };
+
+    available_time - 2 * first + 1
+}
+
+#[test]
+fn test_example() {
+    let a = ways_to_beat_record(7, 9);
+    let b = ways_to_beat_record(15, 40);
+    let c = ways_to_beat_record(30, 200);
+
+    assert_eq!(a, 4);
+    assert_eq!(b, 8);
+    assert_eq!(c, 9);
+
+    let ans = a * b * c;
+    assert_eq!(ans, 288);
+
+    let part_two = ways_to_beat_record(71530, 940200);
+    assert_eq!(part_two, 71503);
+}
+
+#[test]
+fn test_parse() {
+    let sheet = parse(include_str!("example")).unwrap();
+    assert_eq!(
+        sheet.races[1],
+        Race {
+            time: 15,
+            distance: 40
+        }
+    );
+    assert_eq!(
+        sheet.kerned,
+        Race {
+            time: 71530,
+            distance: 940200
+        }
+    );
+    assert_eq!(part_one(&sheet), 288);
+    assert_eq!(part_two(&sheet), 71503);
+
+    let error = parse("Time: 7 15\nDistance: 9\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 10: expected 2 distances, one per time, found ` 9`"
+    );
+    assert!(parse("Time: 7\n").is_err());
+}
+
+#[test]
+fn test_ways_to_beat_record() {
+    for time in 0..60 {
+        for distance in 0..=time * time / 4 + 1 {
+            let brute_force = (0..=time).filter(|h| h * (time - h) > distance).count();
+            assert_eq!(
+                ways_to_beat_record(time, distance),
+                brute_force as u128,
+                "time {}, distance {}",
+                time,
+                distance
+            );
+        }
+    }
+
+    // The roots, 10 and 20, only tie the record.
+    assert_eq!(ways_to_beat_record(30, 200), 9);
+    assert_eq!(ways_to_beat_record(u128::MAX, 0), u128::MAX - 1);
+    // Holding for 1 goes `u128::MAX - 1`, short of the record, from 2 on it goes further.
+    assert_eq!(ways_to_beat_record(u128::MAX, u128::MAX), u128::MAX - 3);
+    assert_eq!(ways_to_beat_record(1 << 64, 1 << 126), 0);
+}
@@ -0,0 +1,396 @@
+//! Day 7: Camel Cards, poker-like hands ranked to win their bids.
+
+use std::{cmp::Reverse, fmt::Display, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 7;
+    type Data = Games;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_games(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+#[test]
+fn test() {
+    let example = parse_games(include_str!("example")).unwrap();
+    let example_one = part_one(&example);
+    assert_eq!(example_one, 6440);
+    let example_two = part_two(&example);
+    assert_eq!(example_two, 5905);
+}
+
+#[test]
+fn test_rules() {
+    let jokers = Rules::jokers();
+    let cards = labels("QJJQ2");
+    assert_eq!(find_type(&cards, &Rules::standard()), HandType::TwoPair);
+    assert_eq!(find_type(&cards, &jokers), HandType::FourOfAKind);
+    assert_eq!(find_type(&labels("JJJJJ"), &jokers), HandType::FiveOfAKind);
+
+    // A full house beats three of a kind, but the joker makes four of a kind.
+    let standard = [
+        "JKKK2 1".parse::<Hand>().unwrap(),
+        "QQQ22 2".parse().unwrap(),
+    ];
+    assert!(standard[0].strength < standard[1].strength);
+    let wild = standard.map(|hand| hand.with_rules(&jokers));
+    assert!(wild[0].strength > wild[1].strength);
+
+    // Three card hands where 2 is wild and aces are low.
+    let house = Rules {
+        labels: labels("KQJT98765432A"),
+        wild: Some(Card::Two),
+        hand_length: 3,
+    };
+    let hands = parse_with("A2K 1\nKKA 2\nAAA 3\n", &house).unwrap();
+    assert_eq!(hands[0].hand_type, HandType::OnePair);
+    assert_eq!(total_winnings(&hands), 1 + 2 * 2 + 3 * 3);
+    assert!(parse_with("AAAA 1\n", &house).is_err());
+}
+
+#[test]
+fn test_strength() {
+    let hand = "T55J5 684".parse::<Hand>().unwrap();
+    assert_eq!(hand.cards(), labels("T55J5"));
+    assert_eq!(
+        hand.cards()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<String>(),
+        "T55J5"
+    );
+
+    // Three of a kind, then 8 for T, 3 for 5 and 9 for J, in 4 bits each.
+    assert_eq!(hand.strength, 3 << 60 | 0x83393 << 40);
+    assert_eq!(hand.with_rules(&Rules::jokers()).strength >> 60, 5);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("32T3K 765\nT55X5 684\n").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 4: expected a card, one of `AKQJT98765432`, found `X`"
+    );
+
+    let error = parse("32T3K 765\nKK677\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.expected, "`<cards> <bid>`");
+}
+
+/// Total winnings with `J` at face value.
+pub fn part_one(games: &Games) -> i64 {
+    total_winnings(&games.standard)
+}
+
+/// Total winnings with `J` as jokers.
+pub fn part_two(games: &Games) -> i64 {
+    total_winnings(&games.jokers)
+}
+
+/// Every bid times its hand's rank, the weakest hand has rank 1.
+pub fn total_winnings(hands: &[Hand]) -> i64 {
+    let mut hands = hands.to_vec();
+    hands.sort_by_key(|hand| Reverse(hand.strength));
+
+    let mut mult: i64 = hands.len().try_into().unwrap();
+
+    let mut sum: i64 = 0;
+    for hand in hands {
+        trace!(
+            "{:?}, {}, {:?}. Rank: {}",
+            hand.cards(),
+            hand.bid,
+            hand.hand_type,
+            mult
+        );
+
+        sum += hand.bid * mult;
+        mult -= 1;
+    }
+
+    sum
+}
+
+/// A card's label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    pub const ALL: [Card; 13] = [
+        Card::Two,
+        Card::Three,
+        Card::Four,
+        Card::Five,
+        Card::Six,
+        Card::Seven,
+        Card::Eight,
+        Card::Nine,
+        Card::Ten,
+        Card::Jack,
+        Card::Queen,
+        Card::King,
+        Card::Ace,
+    ];
+
+    /// The card written as `label`, one of `AKQJT98765432`.
+    pub fn from_label(label: char) -> Option<Card> {
+        Card::ALL.into_iter().find(|card| card.label() == label)
+    }
+
+    pub fn label(self) -> char {
+        "23456789TJQKA".as_bytes()[self as usize] as char
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Most cards a hand can have, so its [`Hand::strength`] fits in a `u64`.
+pub const MAX_HAND_LENGTH: usize = 15;
+
+/// How hands are made and ranked, a house rule variant is just different rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Every label, strongest first.
+    pub labels: Vec<Card>,
+    /// A label that can pretend to be whatever card makes the best type.
+    pub wild: Option<Card>,
+    /// Cards in a hand, at most [`MAX_HAND_LENGTH`].
+    pub hand_length: usize,
+}
+
+impl Rules {
+    /// Part one, cards at face value.
+    pub fn standard() -> Rules {
+        Rules {
+            labels: labels("AKQJT98765432"),
+            wild: None,
+            hand_length: 5,
+        }
+    }
+
+    /// Part two, `J` cards are jokers and the weakest cards on their own.
+    pub fn jokers() -> Rules {
+        Rules {
+            labels: labels("AKQT98765432J"),
+            wild: Some(Card::Jack),
+            hand_length: 5,
+        }
+    }
+
+    /// How strong the card is on its own, 0 for the weakest of [`Rules::labels`].
+    fn rank(&self, card: Card) -> u64 {
+        let position = self.labels.iter().position(|&label| label == card);
+        (self.labels.len() - 1 - position.unwrap_or(self.labels.len() - 1)) as u64
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::standard()
+    }
+}
+
+fn labels(labels: &str) -> Vec<Card> {
+    labels
+        .chars()
+        .map(|label| Card::from_label(label).unwrap())
+        .collect()
+}
+
+/// Strongest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    /// where all five cards have the same label
+    FiveOfAKind,
+    /// where four cards have the same label and one card has a different label
+    FourOfAKind,
+    /// where three cards have the same label, and the remaining two cards share a different label
+    FullHouse,
+    /// where three cards have the same label, and the remaining two cards are each different from any other card in the hand
+    ThreeOfAKind,
+    /// where two cards share one label, two other cards share a second label, and the remaining card has a third label
+    TwoPair,
+    /// where two cards share one label, and the other three cards have a different label from the pair and each other
+    OnePair,
+    /// where all cards' labels are distinct
+    HighCard,
+}
+
+/// A line like `32T3K 765`, ranked by its [`Hand::strength`] under the rules it was made with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub bid: i64,
+    cards: [Card; MAX_HAND_LENGTH],
+    length: usize,
+    /// Best type the wild cards can make.
+    pub hand_type: HandType,
+    /// Higher for stronger hands: the type in the top bits, then the rank of each card, 4 bits each.
+    pub strength: u64,
+}
+
+impl Hand {
+    /// At most [`MAX_HAND_LENGTH`] cards, with labels from the rules.
+    pub fn new(cards: &[Card], bid: i64, rules: &Rules) -> Hand {
+        let hand_type = find_type(cards, rules);
+
+        let mut strength = (HandType::HighCard as u64 - hand_type as u64) << 60;
+        for (i, &card) in cards.iter().enumerate() {
+            strength |= rules.rank(card) << (56 - 4 * i);
+        }
+
+        let mut padded = [Card::Two; MAX_HAND_LENGTH];
+        padded[..cards.len()].copy_from_slice(cards);
+
+        Hand {
+            bid,
+            cards: padded,
+            length: cards.len(),
+            hand_type,
+            strength,
+        }
+    }
+
+    /// In the order they were dealt.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards[..self.length]
+    }
+
+    /// The same cards and bid ranked by other rules.
+    pub fn with_rules(&self, rules: &Rules) -> Hand {
+        Hand::new(self.cards(), self.bid, rules)
+    }
+
+    /// The cards and the bid, like `32T3K 765`.
+    pub fn parse_with(s: &str, rules: &Rules) -> Result<Hand, ParseError> {
+        let (cards, bid) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s, s, "`<cards> <bid>`"))?;
+        let bid = parse::number::<i64>(s, bid.trim())?;
+
+        let mut parsed = Vec::new();
+        for (i, label) in cards.char_indices() {
+            match Card::from_label(label) {
+                Some(card) if rules.labels.contains(&card) => parsed.push(card),
+                _ => {
+                    let found = &cards[i..i + label.len_utf8()];
+                    let labels = rules.labels.iter().map(|c| c.label()).collect::<String>();
+                    return Err(ParseError::new(
+                        s,
+                        found,
+                        format!("a card, one of `{}`", labels),
+                    ));
+                }
+            }
+        }
+        if parsed.len() != rules.hand_length || parsed.len() > MAX_HAND_LENGTH {
+            return Err(ParseError::new(
+                s,
+                cards,
+                format!("{} cards", rules.hand_length),
+            ));
+        }
+
+        Ok(Hand::new(&parsed, bid, rules))
+    }
+}
+
+/// Type of the cards, wild cards joining the most common other label. With more than five cards the two most
+/// common labels decide, e.g. six of a kind counts as five of a kind.
+pub fn find_type(cards: &[Card], rules: &Rules) -> HandType {
+    let mut counts = [0; 13];
+    let mut wild = 0;
+    for &card in cards {
+        if Some(card) == rules.wild {
+            wild += 1;
+        } else {
+            counts[card as usize] += 1;
+        }
+    }
+
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    match (counts[0] + wild, counts[1]) {
+        (5.., _) => HandType::FiveOfAKind,
+        (4, _) => HandType::FourOfAKind,
+        (3, 2..) => HandType::FullHouse,
+        (3, _) => HandType::ThreeOfAKind,
+        (2, 2..) => HandType::TwoPair,
+        (2, _) => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    /// A hand under [`Rules::standard`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hand::parse_with(s, &Rules::standard())
+    }
+}
+
+/// The same hands under the rules of each part, so neither has to rank them again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Games {
+    /// Under [`Rules::standard`].
+    pub standard: Vec<Hand>,
+    /// Under [`Rules::jokers`].
+    pub jokers: Vec<Hand>,
+}
+
+/// One hand per line, parsed once for each part's rules.
+pub fn parse_games(input: &str) -> Result<Games, ParseError> {
+    Ok(Games {
+        standard: parse(input)?,
+        jokers: parse_with(input, &Rules::jokers())?,
+    })
+}
+
+/// One hand per line, under [`Rules::standard`].
+pub fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+    parse_with(input, &Rules::standard())
+}
+
+/// One hand per line, under house `rules`.
+pub fn parse_with(input: &str, rules: &Rules) -> Result<Vec<Hand>, ParseError> {
+    input
+        .lines()
+        .map(|line| Hand::parse_with(line, rules).map_err(|e| e.within(input, line)))
+        .collect()
+}
@@ -0,0 +1,567 @@
+//! Day 8: Haunted Wasteland, following left/right instructions through a network of nodes.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use log::debug;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 8;
+    type Data = Data;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        match part_one(data) {
+            Some(steps) => Some(steps.to_string()),
+            None => Some("unreachable".to_string()),
+        }
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        match part_two(data) {
+            Some(steps) => Some(steps.to_string()),
+            None => Some("unreachable".to_string()),
+        }
+    }
+}
+
+/// Steps from `AAA` to `ZZZ`, `None` if either is missing or the walk never gets there.
+pub fn part_one(data: &Data) -> Option<u64> {
+    ghost_walks(data, |name| name == "AAA", |name| name == "ZZZ")
+}
+
+/// The nodes by index, with their children looked up once.
+#[derive(Debug)]
+pub struct Network {
+    pub names: Vec<String>,
+    /// Left and right child of every node.
+    pub children: Vec<(usize, usize)>,
+    indices: HashMap<String, usize>,
+}
+
+impl Network {
+    /// Every child has to be one of the nodes, as [`parse`] makes sure.
+    pub fn new(nodes: &[NodeData]) -> Network {
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.name.clone(), i))
+            .collect::<HashMap<_, _>>();
+        let children = nodes
+            .iter()
+            .map(|node| (indices[&node.left], indices[&node.right]))
+            .collect();
+
+        Network {
+            names: nodes.iter().map(|node| node.name.clone()).collect(),
+            children,
+            indices,
+        }
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    /// The node `instruction` leads to from `node`.
+    pub fn next(&self, node: usize, instruction: &Instruction) -> usize {
+        match instruction {
+            Instruction::Left => self.children[node].0,
+            Instruction::Right => self.children[node].1,
+        }
+    }
+
+    /// Indices of the nodes whose name `predicate` accepts.
+    pub fn nodes_where(&self, predicate: impl Fn(&str) -> bool) -> Vec<usize> {
+        (0..self.names.len())
+            .filter(|&i| predicate(&self.names[i]))
+            .collect()
+    }
+
+    /// The network in Graphviz DOT, edges labelled with the instruction taking them. Start nodes are drawn green and
+    /// end nodes red, render it with e.g. `dot -Tsvg`.
+    pub fn to_dot(&self, is_start: impl Fn(&str) -> bool, is_end: impl Fn(&str) -> bool) -> String {
+        let mut dot = String::from("digraph network {\n");
+        for name in &self.names {
+            let color = match (is_start(name), is_end(name)) {
+                (true, _) => " [style=filled, fillcolor=palegreen]",
+                (_, true) => " [style=filled, fillcolor=salmon]",
+                _ => "",
+            };
+            writeln!(dot, "    \"{}\"{};", name, color).unwrap();
+        }
+
+        for (name, &(left, right)) in self.names.iter().zip(&self.children) {
+            let edges = if left == right {
+                vec![(left, "LR")]
+            } else {
+                vec![(left, "L"), (right, "R")]
+            };
+            for (child, label) in edges {
+                writeln!(
+                    dot,
+                    "    \"{}\" -> \"{}\" [label={}];",
+                    name, self.names[child], label
+                )
+                .unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Steps at which a walk is at a node ending in `Z`, once it's found going round in a cycle.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Hits before the cycle starts, they don't come back.
+    pub hits_before: Vec<u64>,
+    /// Steps until the walk first reaches a node and instruction it gets back to.
+    pub start: u64,
+    pub length: u64,
+    /// Hits within the first time round the cycle, each comes back every `length` steps.
+    pub hits: Vec<u64>,
+}
+
+impl Cycle {
+    /// Whether the walk is at an end after `steps`.
+    pub fn contains(&self, steps: u64) -> bool {
+        self.hits_before.contains(&steps)
+            || self
+                .hits
+                .iter()
+                .any(|&hit| steps >= hit && (steps - hit).is_multiple_of(self.length))
+    }
+}
+
+/// Walk from `start` until a node is reached at the same point of the instructions a second time.
+fn find_cycle(
+    network: &Network,
+    instructions: &[Instruction],
+    start: usize,
+    is_end: &[bool],
+) -> Cycle {
+    let mut seen = vec![None; network.names.len() * instructions.len()];
+    let mut ends = Vec::new();
+
+    let mut current = start;
+    let mut steps = 0;
+    loop {
+        let i = steps % instructions.len();
+        let state = current * instructions.len() + i;
+        if let Some(first) = seen[state] {
+            let (hits_before, hits) = ends.iter().partition(|&&hit| hit < first as u64);
+            return Cycle {
+                hits_before,
+                start: first as u64,
+                length: (steps - first) as u64,
+                hits,
+            };
+        }
+        seen[state] = Some(steps);
+
+        if is_end[current] {
+            ends.push(steps as u64);
+        }
+
+        current = network.next(current, &instructions[i]);
+        steps += 1;
+    }
+}
+
+/// Steps until every walk from a node ending in `A` is at a node ending in `Z` at once, `None` if that never happens.
+pub fn part_two(data: &Data) -> Option<u64> {
+    ghost_walks(data, |name| name.ends_with('A'), |name| name.ends_with('Z'))
+}
+
+/// Steps until walks from every node `is_start` accepts are all at nodes `is_end` accepts, `None` if that never
+/// happens or there is no such start.
+pub fn ghost_walks(
+    data: &Data,
+    is_start: impl Fn(&str) -> bool,
+    is_end: impl Fn(&str) -> bool,
+) -> Option<u64> {
+    let network = Network::new(&data.nodes);
+    let ends = network
+        .names
+        .iter()
+        .map(|name| is_end(name))
+        .collect::<Vec<_>>();
+
+    let cycles = network
+        .nodes_where(is_start)
+        .into_iter()
+        .map(|start| find_cycle(&network, &data.instructions, start, &ends))
+        .collect::<Vec<_>>();
+    debug!("Cycles: {:?}", cycles);
+
+    if cycles.is_empty() {
+        return None;
+    }
+    all_at_end(&cycles)
+}
+
+/// First step at which every cycle is at an end, `None` if there is none.
+pub fn all_at_end(cycles: &[Cycle]) -> Option<u64> {
+    // A hit before a cycle starts only comes once, so it's enough to check it against the others.
+    let once = cycles
+        .iter()
+        .flat_map(|cycle| cycle.hits_before.iter().copied())
+        .filter(|&steps| cycles.iter().all(|cycle| cycle.contains(steps)))
+        .min();
+
+    // Every other answer is some hit of each cycle coming back, found by combining them a cycle at a time. A residue,
+    // its modulus and the least steps it's valid from.
+    let mut combined = vec![(0, 1, 0)];
+    for cycle in cycles {
+        combined = combined
+            .into_iter()
+            .flat_map(|(residue, modulus, from)| {
+                cycle.hits.iter().filter_map(move |&hit| {
+                    let (residue, modulus) = crt((residue, modulus), (hit, cycle.length))?;
+                    Some((residue, modulus, std::cmp::max(from, hit)))
+                })
+            })
+            .collect();
+    }
+
+    let repeated = combined
+        .into_iter()
+        .map(|(residue, modulus, from)| {
+            if residue >= from {
+                residue
+            } else {
+                residue + (from - residue).div_ceil(modulus) * modulus
+            }
+        })
+        .min();
+
+    match (once, repeated) {
+        (Some(a), Some(b)) => Some(std::cmp::min(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// The numbers that are `a.0` modulo `a.1` and `b.0` modulo `b.1`, as a residue modulo the least common multiple of
+/// the moduli. `None` if there are none, the moduli don't have to be coprime.
+pub fn crt(a: (u64, u64), b: (u64, u64)) -> Option<(u64, u64)> {
+    let (r1, m1) = (a.0 as i128, a.1 as i128);
+    let (r2, m2) = (b.0 as i128, b.1 as i128);
+
+    // p * m1 + q * m2 = g
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+
+    let step = m2 / g;
+    let k = ((r2 - r1) / g % step * (p % step)).rem_euclid(step);
+    let modulus = m1 * step;
+    let residue = (r1 + m1 * k).rem_euclid(modulus);
+
+    Some((residue.try_into().ok()?, modulus.try_into().ok()?))
+}
+
+/// Greatest common divisor `g` of `a` and `b`, with `p` and `q` such that `p * a + q * b = g`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+
+    let (g, p, q) = extended_gcd(b, a % b);
+    (g, q, p - a / b * q)
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+    assert_eq!(crt((2, 4), (4, 6)), Some((10, 12)));
+    assert_eq!(crt((1, 4), (2, 6)), None);
+    assert_eq!(crt((0, 1), (7, 9)), Some((7, 9)));
+}
+
+#[test]
+fn test_cycles() {
+    // 11A hits 11Z after 2, 5, 8... steps, 22A hits 22Z after every odd number of steps.
+    let input = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)
+";
+    let data = parse(input).unwrap();
+    let network = Network::new(&data.nodes);
+    let ends = network
+        .names
+        .iter()
+        .map(|n| n.ends_with('Z'))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        find_cycle(&network, &data.instructions, 0, &ends),
+        Cycle {
+            hits_before: vec![],
+            start: 1,
+            length: 3,
+            hits: vec![2],
+        }
+    );
+    assert_eq!(part_two(&data), Some(5));
+
+    // 11A is only at 11Z after a single step.
+    let once = input.replace("11B = (11Z, 11Z)", "11B = (11B, 11B)");
+    let once = once.replace("11A = (11B, 11B)", "11A = (11Z, 11Z)");
+    assert_eq!(part_two(&parse(&once).unwrap()), Some(1));
+
+    // 22A is at 22Z after every even number of steps instead.
+    let never = once.replace("22A = (22Z, 22Z)", "22A = (22B, 22B)");
+    assert_eq!(part_two(&parse(&never).unwrap()), None);
+}
+
+/// Least common multiple, 0 if either number is. Divides before multiplying so only a multiple too large for `u64`
+/// overflows.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    match gcd(a, b) {
+        0 => 0,
+        divisor => a / divisor * b,
+    }
+}
+
+#[test]
+fn test_lcm() {
+    let n = lcm(1, 2);
+    assert_eq!(n, 2);
+
+    let n = lcm(3, 7);
+    assert_eq!(n, 21);
+
+    let n = lcm(12, 15);
+    assert_eq!(n, 60);
+
+    let n = lcm(0, 5);
+    assert_eq!(n, 0);
+
+    let n = lcm(1 << 40, 3 << 40);
+    assert_eq!(n, 3 << 40);
+}
+
+/// Greatest common divisor by Euclid's algorithm, the other number if one is 0.
+pub fn gcd(num_a: u64, num_b: u64) -> u64 {
+    let mut a = num_a;
+    let mut b = num_b;
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+#[test]
+fn test_gcd() {
+    let a = gcd(21, 14);
+    assert_eq!(a, 7);
+
+    let a = gcd(81, 45);
+    assert_eq!(a, 9);
+
+    let a = gcd(121, 144);
+    assert_eq!(a, 1);
+
+    let a = gcd(56, 48);
+    assert_eq!(a, 8);
+
+    let a = gcd(10, 5);
+    assert_eq!(a, 5);
+
+    let a = gcd(10, 10);
+    assert_eq!(a, 10);
+
+    let a = gcd(0, 0);
+    assert_eq!(a, 0);
+
+    let a = gcd(0, 12);
+    assert_eq!(a, 12);
+
+    let a = gcd(12, 0);
+    assert_eq!(a, 12);
+}
+
+/// A line like `AAA = (BBB, CCC)`.
+#[derive(Debug)]
+pub struct NodeData {
+    pub name: String,
+    pub left: String,
+    pub right: String,
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+/// The map: instructions, repeated as needed, and the network.
+pub struct Data {
+    pub instructions: Vec<Instruction>,
+    pub nodes: Vec<NodeData>,
+}
+
+/// The instructions on the first line, then a node per line like `AAA = (BBB, CCC)`.
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    let mut lines = input.lines();
+
+    let first = lines.next().unwrap_or_default();
+    let mut instructions = Vec::new();
+    for (i, char) in first.char_indices() {
+        match char {
+            'R' => instructions.push(Instruction::Right),
+            'L' => instructions.push(Instruction::Left),
+            _ => {
+                let found = &first[i..i + char.len_utf8()];
+                return Err(ParseError::new(input, found, "`L` or `R`"));
+            }
+        }
+    }
+
+    if instructions.is_empty() {
+        return Err(ParseError::new(input, first, "at least one `L` or `R`"));
+    }
+
+    let mut nodes = Vec::new();
+    let mut children = Vec::new();
+    for line in lines.filter(|line| !line.is_empty()) {
+        let (name, child_nodes) = parse::split_once(input, line, " = ")?;
+        let child_nodes = parse::strip_prefix(input, child_nodes, "(")?;
+        let child_nodes = child_nodes
+            .strip_suffix(')')
+            .ok_or_else(|| ParseError::new(input, line, "`)` at the end"))?;
+        let (left, right) = parse::split_once(input, child_nodes, ", ")?;
+
+        children.extend([left, right]);
+        nodes.push(NodeData {
+            name: name.to_string(),
+            left: left.to_string(),
+            right: right.to_string(),
+        });
+    }
+
+    // Every node has to lead somewhere, the walks look up the children by name.
+    let names = nodes
+        .iter()
+        .map(|n| n.name.as_str())
+        .collect::<HashSet<_>>();
+    if let Some(missing) = children.into_iter().find(|c| !names.contains(c)) {
+        return Err(ParseError::new(input, missing, "the name of a node"));
+    }
+
+    Ok(Data {
+        nodes,
+        instructions,
+    })
+}
+
+#[test]
+fn test_example() {
+    let data1 = parse(include_str!("example_1")).unwrap();
+    let steps1 = part_one(&data1);
+    assert_eq!(steps1, Some(2));
+
+    let data2 = parse(include_str!("example_2")).unwrap();
+    let steps2 = part_one(&data2);
+    assert_eq!(steps2, Some(6));
+
+    let data_input = parse(include_str!("input")).unwrap();
+    let steps_input = part_one(&data_input);
+    assert_eq!(steps_input, Some(15989));
+
+    let data3 = parse(include_str!("example_3")).unwrap();
+    let steps3 = part_two(&data3);
+    assert_eq!(steps3, Some(6));
+
+    let data_part_two = parse(include_str!("input")).unwrap();
+    let steps_part_two = part_two(&data_part_two);
+    assert_eq!(steps_part_two, Some(13830919117339));
+}
+
+#[test]
+fn test_unreachable() {
+    let data = parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap();
+    assert_eq!(part_one(&data), None);
+    assert_eq!(Solution::part_one(&data), Some("unreachable".to_string()));
+
+    let data = parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n").unwrap();
+    assert_eq!(part_one(&data), None);
+
+    let data = parse("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (AAA, AAA)\n").unwrap();
+    assert_eq!(part_one(&data), Some(1));
+}
+
+#[test]
+fn test_network() {
+    let data =
+        parse("L\n\nAAA = (BBB, CCC)\nBBB = (ZZZ, ZZZ)\nCCC = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")
+            .unwrap();
+    let network = Network::new(&data.nodes);
+    assert_eq!(network.index("CCC"), Some(2));
+    assert_eq!(network.children[2], (0, 3));
+    assert_eq!(network.nodes_where(|n| n < "BBC"), [0, 1]);
+
+    // Going left everything ends up staying at ZZZ, CCC last after three steps.
+    assert_eq!(ghost_walks(&data, |n| n != "ZZZ", |n| n == "ZZZ"), Some(3));
+
+    let dot = network.to_dot(|n| n == "AAA", |n| n == "ZZZ");
+    let expected = "\
+digraph network {
+    \"AAA\" [style=filled, fillcolor=palegreen];
+    \"BBB\";
+    \"CCC\";
+    \"ZZZ\" [style=filled, fillcolor=salmon];
+    \"AAA\" -> \"BBB\" [label=L];
+    \"AAA\" -> \"CCC\" [label=R];
+    \"BBB\" -> \"ZZZ\" [label=LR];
+    \"CCC\" -> \"AAA\" [label=L];
+    \"CCC\" -> \"ZZZ\" [label=R];
+    \"ZZZ\" -> \"ZZZ\" [label=LR];
+}
+";
+    assert_eq!(dot, expected);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 4, column 13: expected the name of a node, found `ZZZ`"
+    );
+
+    let error = parse("LRX\n\nAAA = (AAA, AAA)\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 3));
+
+    let error = parse("\n\nAAA = (AAA, AAA)\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.expected, "at least one `L` or `R`");
+
+    let error = parse("LR\n\nAAA = AAA, AAA\n").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 7));
+    assert_eq!(error.expected, "`(`");
+}
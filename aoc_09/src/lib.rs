@@ -0,0 +1,199 @@
+//! Day 9: Mirage Maintenance, extrapolating sequences by their differences.
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 9;
+    type Data = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        part_one(data).map(|sum| sum.to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        part_two(data).map(|sum| sum.to_string())
+    }
+}
+
+/// Sum of the next value of every sequence, `None` if a value or the sum doesn't fit in an `i64`.
+pub fn part_one(data: &[Vec<i64>]) -> Option<i64> {
+    let mut sum: i64 = 0;
+
+    for nums in data {
+        let prediction = predict(nums)?;
+        sum = sum.checked_add(prediction)?;
+        trace!("For {:?} predicted: {}", nums, prediction);
+    }
+
+    Some(sum)
+}
+
+/// Sum of the value before the first of every sequence, `None` if a value or the sum doesn't fit in an `i64`.
+pub fn part_two(data: &[Vec<i64>]) -> Option<i64> {
+    let mut sum: i64 = 0;
+
+    for nums in data {
+        let history = extrapolate_backwards(nums)?;
+        sum = sum.checked_add(history)?;
+        trace!("For {:?} extrapolated history: {}", nums, history);
+    }
+
+    Some(sum)
+}
+
+#[test]
+fn test() {
+    let example = include_str!("example");
+    let ex_data = parse(example).unwrap();
+    let a = part_one(&ex_data);
+    assert_eq!(a, Some(114));
+
+    let b = part_two(&ex_data);
+    assert_eq!(b, Some(2));
+
+    let input = include_str!("input");
+    let data = parse(input).unwrap();
+    let a = part_one(&data);
+    assert_eq!(a, Some(1969958987));
+}
+
+#[test]
+fn test_overflow() {
+    let data = parse("0 9223372036854775807\n").unwrap();
+    assert_eq!(predict(&data[0]), None);
+    assert_eq!(extrapolate_backwards(&data[0]), Some(-9223372036854775807));
+    assert_eq!(Solution::part_one(&data), None);
+
+    let data = parse("9223372036854775807 0\n").unwrap();
+    assert_eq!(part_two(&data), None);
+
+    // Each prediction fits, their sum doesn't.
+    let data = parse("4611686018427387904 4611686018427387904\n4611686018427387904\n").unwrap();
+    assert_eq!(predict(&data[0]), Some(4611686018427387904));
+    assert_eq!(part_one(&data), None);
+}
+
+/// The lowest degree polynomial through a sequence, in Newton form: the value at index `x` is the sum of
+/// `coefficients[k] * C(x, k)`, where the sequence starts at index 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// The first number of every row of differences, without trailing zeros.
+    pub coefficients: Vec<i128>,
+}
+
+impl Polynomial {
+    /// `None` if the differences don't fit in an `i128`.
+    pub fn fit(numbers: &[i64]) -> Option<Polynomial> {
+        let mut row = numbers.iter().map(|&n| n as i128).collect::<Vec<_>>();
+        let mut coefficients = Vec::new();
+        while let Some(&first) = row.first() {
+            coefficients.push(first);
+            row = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<_>>()?;
+        }
+
+        while coefficients.last() == Some(&0) {
+            coefficients.pop();
+        }
+
+        Some(Polynomial { coefficients })
+    }
+
+    /// 0 for constants, including zero.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    /// The value at `index`, negative ones come before the sequence. `None` if it doesn't fit in an `i128`.
+    pub fn evaluate(&self, index: i64) -> Option<i128> {
+        let x = index as i128;
+
+        let mut value: i128 = 0;
+        // C(x, k), the product of `x - j` for every `j < k` divides by `k!` exactly at every step.
+        let mut binomial: i128 = 1;
+        for (k, &coefficient) in self.coefficients.iter().enumerate() {
+            if k > 0 {
+                let k = k as i128;
+                binomial = binomial.checked_mul(x - k + 1)? / k;
+            }
+            value = value.checked_add(coefficient.checked_mul(binomial)?)?;
+        }
+
+        Some(value)
+    }
+}
+
+/// The value following `numbers`, `None` if it doesn't fit in an `i64`.
+pub fn predict(numbers: &[i64]) -> Option<i64> {
+    let polynomial = Polynomial::fit(numbers)?;
+    let next = polynomial.evaluate(numbers.len() as i64)?;
+
+    next.try_into().ok()
+}
+
+/// The value preceding `numbers`, `None` if it doesn't fit in an `i64`.
+pub fn extrapolate_backwards(numbers: &[i64]) -> Option<i64> {
+    let polynomial = Polynomial::fit(numbers)?;
+    let previous = polynomial.evaluate(-1)?;
+
+    previous.try_into().ok()
+}
+
+/// A sequence of numbers per line.
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse::numbers(input, line))
+        .collect()
+}
+
+#[test]
+fn test_polynomial() {
+    let data = parse(include_str!("example")).unwrap();
+    let fits = data
+        .iter()
+        .map(|numbers| Polynomial::fit(numbers).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        fits.iter().map(|p| p.degree()).collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert_eq!(fits[0].coefficients, [0, 3]);
+    assert_eq!(fits[2].evaluate(6), Some(68));
+    assert_eq!(fits[2].evaluate(-1), Some(5));
+
+    let squares = Polynomial::fit(&[0, 1, 4, 9]).unwrap();
+    assert_eq!(squares.evaluate(1_000_000), Some(1_000_000_000_000));
+    assert_eq!(squares.evaluate(-5), Some(25));
+
+    let cubes = Polynomial::fit(&[0, 1, 8, 27, 64]).unwrap();
+    assert_eq!(cubes.degree(), 3);
+    assert_eq!(cubes.evaluate(-3), Some(-27));
+    assert_eq!(cubes.evaluate(i64::MAX), None);
+
+    assert_eq!(Polynomial::fit(&[7, 7, 7]).unwrap().degree(), 0);
+    assert_eq!(Polynomial::fit(&[]).unwrap().evaluate(10), Some(0));
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("0 3 6 9\n1 3 6 1O\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 7: expected a number, found `1O`"
+    );
+}
@@ -0,0 +1,374 @@
+//! Day 10: Pipe Maze, a loop of pipes through a field of tiles.
+
+use std::{collections::HashSet, fmt::Write};
+
+use common::{parse::ParseError, Solver};
+use grid::{Direction, Grid, Position};
+use log::debug;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 10;
+    type Data = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        part_two(data).map(|tiles| tiles.to_string())
+    }
+}
+
+/// The field, a tile per character, with the start at `S`.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Whether the pipe `symbol` connects back to a tile it was reached from going `dir`.
+// FIXME: consider current pipe type.
+fn is_connected(dir: Direction, symbol: char) -> bool {
+    match dir {
+        Direction::Up => symbol == '|' || symbol == '7' || symbol == 'F',
+        Direction::Down => symbol == '|' || symbol == 'L' || symbol == 'J',
+        Direction::Left => symbol == '-' || symbol == 'F' || symbol == 'L',
+        Direction::Right => symbol == '-' || symbol == 'J' || symbol == '7',
+    }
+}
+
+/// Every pipe and the two directions it connects.
+const PIPES: [(char, [Direction; 2]); 6] = [
+    ('|', [Direction::Up, Direction::Down]),
+    ('-', [Direction::Left, Direction::Right]),
+    ('L', [Direction::Up, Direction::Right]),
+    ('J', [Direction::Up, Direction::Left]),
+    ('7', [Direction::Down, Direction::Left]),
+    ('F', [Direction::Down, Direction::Right]),
+];
+
+/// The two directions the pipe `symbol` connects, `None` for ground and the start.
+pub fn connections(symbol: char) -> Option<[Direction; 2]> {
+    PIPES
+        .iter()
+        .find(|(pipe, _)| *pipe == symbol)
+        .map(|(_, directions)| *directions)
+}
+
+/// The pipe under `start`, the one connecting two neighbours whose pipes lead to it and which [`main_loop`] follows
+/// back around to `start`.
+pub fn start_shape(map: &Grid<char>, start: Position) -> Option<char> {
+    let connected = Direction::ALL
+        .into_iter()
+        .filter(|&dir| {
+            map.step(start, dir)
+                .and_then(|next| connections(map[next]))
+                .is_some_and(|pipes| pipes.contains(&dir.opposite()))
+        })
+        .collect::<Vec<_>>();
+
+    PIPES
+        .iter()
+        .find(|(_, [a, b])| {
+            connected.contains(a)
+                && connected.contains(b)
+                && follow(map, start, *a).is_some_and(|(_, arrival)| arrival == b.opposite())
+        })
+        .map(|(pipe, _)| *pipe)
+}
+
+/// The tiles of the loop through `S` in order, starting with `S`. `None` if there's no start or its pipes don't make
+/// a loop.
+pub fn main_loop(map: &Grid<char>) -> Option<Vec<Position>> {
+    let start = map.find(&'S')?;
+    let [dir, _] = connections(start_shape(map, start)?)?;
+    follow(map, start, dir).map(|(tiles, _)| tiles)
+}
+
+/// The tiles from `start` leaving going `dir` until back at `start`, and the direction it's reached in. `None` if the
+/// pipes lead off the map or into one that doesn't connect.
+fn follow(
+    map: &Grid<char>,
+    start: Position,
+    mut dir: Direction,
+) -> Option<(Vec<Position>, Direction)> {
+    let mut tiles = vec![start];
+    let mut current = map.step(start, dir)?;
+    while current != start {
+        tiles.push(current);
+
+        let [a, b] = connections(map[current])?;
+        dir = match dir.opposite() {
+            from if from == a => b,
+            from if from == b => a,
+            _ => return None,
+        };
+        current = map.step(current, dir)?;
+    }
+
+    Some((tiles, dir))
+}
+
+/// Tiles enclosed by the loop.
+///
+/// The shoelace formula gives the area inside the loop through the centers of its tiles, and Pick's theorem turns it
+/// into the tiles strictly inside: `area = inside + tiles / 2 - 1`.
+pub fn part_two(map: &Grid<char>) -> Option<usize> {
+    let tiles = main_loop(map)?;
+    debug!(
+        "Loop of {} tiles:\n{}",
+        tiles.len(),
+        render(map, RenderOptions::default().inside())
+    );
+
+    let twice_area = tiles
+        .iter()
+        .zip(tiles.iter().cycle().skip(1))
+        .map(|(a, b)| (a.row * b.col) as i64 - (b.row * a.col) as i64)
+        .sum::<i64>()
+        .unsigned_abs() as usize;
+
+    Some((twice_area + 2 - tiles.len()) / 2)
+}
+
+/// Tiles inside the loop, `loop_tiles` being [`main_loop`]'s.
+///
+/// Going along a row, every loop pipe that connects up crosses from outside to inside or back.
+pub fn enclosed(map: &Grid<char>, loop_tiles: &[Position]) -> Grid<bool> {
+    let shapes = loop_shapes(map, loop_tiles);
+
+    let mut inside = Grid::new(map.width(), map.height(), false);
+    for row in 0..map.height() {
+        let mut crossings = 0;
+        for col in 0..map.width() {
+            let pos = Position::new(row, col);
+            match shapes[pos] {
+                Some(pipe) if connections(pipe).unwrap().contains(&Direction::Up) => crossings += 1,
+                Some(_) => {}
+                None => inside[pos] = crossings % 2 == 1,
+            }
+        }
+    }
+
+    inside
+}
+
+/// The pipe of every loop tile, with the start's shape in place of `S`.
+fn loop_shapes(map: &Grid<char>, loop_tiles: &[Position]) -> Grid<Option<char>> {
+    let mut shapes = Grid::new(map.width(), map.height(), None);
+    for &pos in loop_tiles {
+        shapes[pos] = Some(map[pos]);
+    }
+    if let Some(&start) = loop_tiles.first() {
+        shapes[start] = start_shape(map, start);
+    }
+
+    shapes
+}
+
+/// How [`render`] draws the map.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions {
+    /// ANSI escape codes for a terminal: pipes off the loop dimmed and tiles inside it green. Leave it off when
+    /// writing to a file.
+    pub ansi: bool,
+    /// Show the tiles inside the loop, as `I` without ANSI colours.
+    pub inside: bool,
+}
+
+impl RenderOptions {
+    pub fn ansi(self) -> Self {
+        RenderOptions { ansi: true, ..self }
+    }
+
+    pub fn inside(self) -> Self {
+        RenderOptions {
+            inside: true,
+            ..self
+        }
+    }
+}
+
+const DIM: &str = "\x1b[2m";
+const GREEN: &str = "\x1b[42m";
+const RESET: &str = "\x1b[0m";
+
+/// The map with the main loop drawn in box-drawing characters, a line per row. The rest of the map is drawn as it
+/// was read.
+pub fn render(map: &Grid<char>, options: RenderOptions) -> String {
+    let loop_tiles = main_loop(map).unwrap_or_default();
+    let shapes = loop_shapes(map, &loop_tiles);
+    let inside = enclosed(map, &loop_tiles);
+
+    let mut text = String::new();
+    for row in 0..map.height() {
+        for col in 0..map.width() {
+            let pos = Position::new(row, col);
+            let tile = map[pos];
+            match shapes[pos] {
+                Some(pipe) => text.push(box_drawing(pipe)),
+                None if options.inside && inside[pos] && options.ansi => {
+                    write!(text, "{}{}{}", GREEN, tile, RESET).unwrap()
+                }
+                None if options.inside && inside[pos] => text.push('I'),
+                None if options.ansi && connections(tile).is_some() => {
+                    write!(text, "{}{}{}", DIM, tile, RESET).unwrap()
+                }
+                None => text.push(tile),
+            }
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+fn box_drawing(pipe: char) -> char {
+    match pipe {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        _ => pipe,
+    }
+}
+
+/// Steps along the loop to the point farthest from the start.
+pub fn part_one(map: &Grid<char>) -> i64 {
+    let start_pos = map.find(&'S').unwrap();
+    let mut visited = HashSet::new();
+    visited.insert(start_pos);
+
+    let mut next_positions = advance_step(map, &start_pos, &mut visited);
+    let mut steps = 0;
+
+    while !next_positions.is_empty() {
+        steps += 1;
+        let mut new_next = Vec::new();
+        for pos in next_positions {
+            let mut new_next_part = advance_step(map, &pos, &mut visited);
+            for temp_debug_only in &new_next_part {
+                assert!(!new_next.contains(temp_debug_only));
+            }
+            new_next.append(&mut new_next_part);
+        }
+        next_positions = new_next;
+    }
+
+    steps
+}
+
+fn advance_step(
+    map: &Grid<char>,
+    position: &Position,
+    visited: &mut HashSet<Position>,
+) -> Vec<Position> {
+    let mut all_visited = true;
+    let mut to_visit = Vec::new();
+
+    assert!(visited.contains(position));
+
+    for dir in Direction::ALL {
+        let Some(next_position) = map.step(*position, dir) else {
+            continue;
+        };
+
+        if is_connected(dir, map[next_position]) {
+            let this_visited = visited.contains(&next_position);
+            all_visited = all_visited && this_visited;
+
+            if !this_visited {
+                to_visit.push(next_position);
+                visited.insert(next_position);
+            }
+        }
+    }
+
+    if all_visited {
+        assert!(to_visit.is_empty());
+    } else {
+        assert!(!to_visit.is_empty());
+    }
+
+    to_visit
+}
+
+#[test]
+fn test() {
+    // Sample output
+
+    // ..45.
+    // .236.
+    // 01.78
+    // 14567
+    // 23...
+
+    let example = Grid::parse(include_str!("example")).unwrap();
+    let a = part_one(&example);
+    assert_eq!(a, 8);
+
+    let input = Grid::parse(include_str!("input")).unwrap();
+    let a = part_one(&input);
+    assert_eq!(a, 6754);
+}
+
+#[test]
+fn test_enclosed() {
+    let example = parse(include_str!("example")).unwrap();
+    let start = example.find(&'S').unwrap();
+    assert_eq!(start_shape(&example, start), Some('F'));
+    let tiles = main_loop(&example).unwrap();
+    assert_eq!(tiles.len(), 16);
+    assert_eq!(tiles[1], Position::new(3, 0));
+    assert_eq!(part_two(&example), Some(1));
+
+    assert_eq!(
+        part_two(&parse(include_str!("example_2")).unwrap()),
+        Some(4)
+    );
+    assert_eq!(
+        part_two(&parse(include_str!("example_3")).unwrap()),
+        Some(8)
+    );
+
+    assert_eq!(part_two(&parse("S7\nLJ\n").unwrap()), Some(0));
+
+    let crowded = parse(".|...\n-S-7.\n.|.|.\n.L-J.\n").unwrap();
+    assert_eq!(start_shape(&crowded, Position::new(1, 1)), Some('F'));
+    assert_eq!(part_one(&crowded), 4);
+    assert_eq!(part_two(&crowded), Some(1));
+
+    assert_eq!(main_loop(&parse("S-7\n|.|\nL--\n").unwrap()), None);
+}
+
+#[test]
+fn test_render() {
+    let example = parse(include_str!("example")).unwrap();
+    let expected = "\
+..┌┐.
+.┌┘│.
+┌┘I└┐
+│┌──┘
+└┘...
+";
+    assert_eq!(
+        render(&example, RenderOptions::default().inside()),
+        expected
+    );
+
+    let inside = enclosed(&example, &main_loop(&example).unwrap());
+    assert_eq!(inside.iter().filter(|(_, &i)| i).count(), 1);
+
+    let map = parse("7S7\n.LJ\n").unwrap();
+    assert_eq!(
+        render(&map, RenderOptions::default().ansi()),
+        "\x1b[2m7\x1b[0m┌┐\n.└┘\n"
+    );
+}
@@ -0,0 +1,306 @@
+//! Day 11: Cosmic Expansion, distances between galaxies in an expanding universe.
+
+use std::fmt::Write;
+
+use common::{parse::ParseError, Solver};
+use log::debug;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 11;
+    type Data = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data, 1000000).to_string())
+    }
+}
+
+/// Sum of the distances between every pair of galaxies, when empty rows and columns are twice as big.
+pub fn part_one(data: &[Vec<bool>]) -> u64 {
+    sum_of_distances(&get_galaxies(data), 2, 2)
+}
+
+/// Like [`part_one`], but empty rows and columns are `expansion_index` times as big.
+pub fn part_two(data: &[Vec<bool>], expansion_index: u64) -> u64 {
+    sum_of_distances(&get_galaxies(data), expansion_index, expansion_index)
+}
+
+/// Sum of the distances between every pair of galaxies, when empty rows are `row_factor` times as big and empty
+/// columns `col_factor` times. The distance is the sum of a row and a column distance, so each axis adds up on its
+/// own.
+pub fn sum_of_distances(galaxies: &[Galaxy], row_factor: u64, col_factor: u64) -> u64 {
+    let rows = galaxies.iter().map(|g| g.row).collect::<Vec<_>>();
+    let cols = galaxies.iter().map(|g| g.col).collect::<Vec<_>>();
+
+    let row_sum = pairwise_distance_sum(expand_axis(&rows, row_factor));
+    let col_sum = pairwise_distance_sum(expand_axis(&cols, col_factor));
+    debug!("Rows add up to {}, columns to {}", row_sum, col_sum);
+
+    row_sum + col_sum
+}
+
+/// Where the coordinates end up, in the same order, when every line along the axis without one of them is `factor`
+/// times as big.
+pub fn expand_axis(coords: &[usize], factor: u64) -> Vec<u64> {
+    let mut occupied = coords.to_vec();
+    occupied.sort_unstable();
+    occupied.dedup();
+
+    coords
+        .iter()
+        .map(|&coord| {
+            let occupied_before = occupied.partition_point(|&c| c < coord) as u64;
+            let empty_before = coord as u64 - occupied_before;
+            occupied_before + empty_before * factor
+        })
+        .collect()
+}
+
+/// Sum of the distances between every pair of numbers. Once sorted, each number is that far from every one before
+/// it: `i * coords[i]` minus the sum of the ones before.
+pub fn pairwise_distance_sum(mut coords: Vec<u64>) -> u64 {
+    coords.sort_unstable();
+
+    let mut before = 0;
+    let mut sum = 0;
+    for (i, coord) in coords.into_iter().enumerate() {
+        sum += i as u64 * coord - before;
+        before += coord;
+    }
+
+    sum
+}
+
+/// The galaxies where they end up, in the same order, when empty rows are `row_factor` times as big and empty columns
+/// `col_factor` times.
+pub fn expanded_galaxies(galaxies: &[Galaxy], row_factor: u64, col_factor: u64) -> Vec<Galaxy> {
+    let rows = galaxies.iter().map(|g| g.row).collect::<Vec<_>>();
+    let cols = galaxies.iter().map(|g| g.col).collect::<Vec<_>>();
+
+    expand_axis(&rows, row_factor)
+        .into_iter()
+        .zip(expand_axis(&cols, col_factor))
+        .map(|(row, col)| Galaxy {
+            row: row as usize,
+            col: col as usize,
+        })
+        .collect()
+}
+
+/// Distance between galaxies `a` and `b` of [`expanded_galaxies`], numbered from 1 in reading order like the puzzle
+/// does. `None` if there's no such galaxy.
+pub fn distance(expanded: &[Galaxy], a: usize, b: usize) -> Option<u64> {
+    let a = expanded.get(a.checked_sub(1)?)?;
+    let b = expanded.get(b.checked_sub(1)?)?;
+
+    Some(calculate_distance(&(a, b)))
+}
+
+/// Number of the closest other galaxy to each galaxy of [`expanded_galaxies`] and how far it is, the lowest number
+/// when several are as close. `None` for a galaxy that's alone.
+pub fn nearest_neighbours(expanded: &[Galaxy]) -> Vec<Option<(usize, u64)>> {
+    expanded
+        .iter()
+        .enumerate()
+        .map(|(i, galaxy)| {
+            expanded
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(j, other)| (j + 1, calculate_distance(&(galaxy, other))))
+                .min_by_key(|&(number, distance)| (distance, number))
+        })
+        .collect()
+}
+
+/// Distances between every two galaxies of [`expanded_galaxies`] as CSV, a header line and a line per galaxy, each
+/// starting with the galaxy's number.
+pub fn distance_matrix_csv(expanded: &[Galaxy]) -> String {
+    let mut csv = String::from("galaxy");
+    for number in 1..=expanded.len() {
+        write!(csv, ",{}", number).unwrap();
+    }
+    csv.push('\n');
+
+    for (i, galaxy) in expanded.iter().enumerate() {
+        write!(csv, "{}", i + 1).unwrap();
+        for other in expanded {
+            write!(csv, ",{}", calculate_distance(&(galaxy, other))).unwrap();
+        }
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Manhattan distance between the galaxies.
+pub fn calculate_distance(pair: &(&Galaxy, &Galaxy)) -> u64 {
+    let x = (pair.0.col as i64 - pair.1.col as i64).unsigned_abs();
+    let y = (pair.0.row as i64 - pair.1.row as i64).unsigned_abs();
+
+    x + y
+}
+
+/// Every unordered pair of distinct elements.
+pub fn get_pairs<T>(data: &[T]) -> Vec<(&T, &T)> {
+    let mut pairs = Vec::new();
+
+    for (i, value) in data.iter().enumerate() {
+        for other in data.iter().skip(i + 1) {
+            pairs.push((value, other));
+        }
+    }
+
+    let combinations = combinations(data.len() as u64, 2);
+    assert_eq!(combinations, pairs.len() as u64);
+
+    pairs
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Galaxy {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// Galaxies row by row.
+pub fn get_galaxies(data: &[Vec<bool>]) -> Vec<Galaxy> {
+    let mut galaxies = Vec::new();
+
+    for (i, row) in data.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            if *value {
+                galaxies.push(Galaxy { row: i, col: j });
+            }
+        }
+    }
+
+    galaxies
+}
+
+/// The image, `#` is a galaxy, `.` empty space.
+pub fn parse(input: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let mut data = Vec::new();
+
+    for line in input.lines() {
+        let mut row = Vec::new();
+        for (i, char) in line.char_indices() {
+            match char {
+                '#' => row.push(true),
+                '.' => row.push(false),
+                _ => {
+                    let found = &line[i..i + char.len_utf8()];
+                    return Err(ParseError::new(input, found, "`#` or `.`"));
+                }
+            }
+        }
+        data.push(row);
+    }
+
+    Ok(data)
+}
+
+/// n: Total items, k: Set size
+fn combinations(n: u64, k: u64) -> u64 {
+    if n < k {
+        return 0;
+    }
+
+    let a: u64 = (n - k + 1..=n).product();
+    let b: u64 = (1..=k).product();
+
+    a / b
+}
+
+#[test]
+fn test_combinations() {
+    let a = combinations(9, 2);
+    assert_eq!(a, 36);
+
+    let a = combinations(100, 2);
+    assert_eq!(a, 4950);
+
+    let a = combinations(100, 4);
+    assert_eq!(a, 3_921_225);
+
+    assert_eq!(combinations(1, 2), 0);
+    assert_eq!(combinations(0, 2), 0);
+}
+
+#[test]
+fn test_pairs() {
+    let pairs = get_pairs(&[1, 2, 3]);
+    assert_eq!(pairs, vec![(&1, &2), (&1, &3), (&2, &3)]);
+
+    assert!(get_pairs::<i32>(&[]).is_empty());
+    assert!(get_pairs(&[1]).is_empty());
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+    assert_eq!(part_one(&example), 374);
+
+    let input = parse(include_str!("input")).unwrap();
+    assert_eq!(part_one(&input), 9545480);
+
+    assert_eq!(part_two(&example, 10), 1030);
+    assert_eq!(part_two(&example, 100), 8410);
+}
+
+#[test]
+fn test_sum_of_distances() {
+    assert_eq!(expand_axis(&[3, 0, 3, 6], 10), [21, 0, 21, 42]);
+    assert_eq!(pairwise_distance_sum(vec![4, 1, 9]), 3 + 8 + 5);
+
+    // Every pair, expanding the galaxies one at a time.
+    let galaxies = get_galaxies(&parse(include_str!("example")).unwrap());
+    for (row_factor, col_factor) in [(1, 1), (2, 5), (1_000_000, 1), (0, 3)] {
+        let expanded = expanded_galaxies(&galaxies, row_factor, col_factor);
+        let expected = get_pairs(&expanded)
+            .iter()
+            .map(calculate_distance)
+            .sum::<u64>();
+
+        assert_eq!(
+            sum_of_distances(&galaxies, row_factor, col_factor),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_queries() {
+    let galaxies = get_galaxies(&parse(include_str!("example")).unwrap());
+    let expanded = expanded_galaxies(&galaxies, 2, 2);
+    assert_eq!(expanded[0], Galaxy { row: 0, col: 4 });
+
+    assert_eq!(distance(&expanded, 5, 9), Some(9));
+    assert_eq!(distance(&expanded, 1, 7), Some(15));
+    assert_eq!(distance(&expanded, 3, 6), Some(17));
+    assert_eq!(distance(&expanded, 8, 9), Some(5));
+    assert_eq!(distance(&expanded, 0, 9), None);
+    assert_eq!(distance(&expanded, 1, 10), None);
+
+    let nearest = nearest_neighbours(&expanded);
+    // Galaxies 7 and 8 are both 5 away from 9.
+    assert_eq!(nearest[8], Some((7, 5)));
+    assert_eq!(nearest_neighbours(&expanded[..1]), [None]);
+
+    let csv = distance_matrix_csv(&expanded[..3]);
+    assert_eq!(csv, "galaxy,1,2,3\n1,0,6,6\n2,6,0,10\n3,6,10,0\n");
+
+    let far = expanded_galaxies(&galaxies, 1_000_000, 1);
+    // Rows 0 to 4 cross the empty row 3, a million rows high.
+    assert_eq!(distance(&far, 1, 4), Some(999_999 + 4 + 3));
+}
@@ -0,0 +1,470 @@
+//! Day 12: Hot Springs, arrangements of damaged springs matching their group sizes.
+
+use std::ops::Add;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 12;
+    type Data = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data, 5).to_string())
+    }
+}
+
+#[test]
+fn test() {
+    let example = include_str!("example");
+    let example_data = parse(example).unwrap();
+
+    let sum = part_one(&example_data);
+    assert_eq!(sum, 21);
+
+    let input = include_str!("input");
+    let input_data = parse(input).unwrap();
+
+    let sum = part_one(&input_data);
+    assert_eq!(sum, 7195);
+
+    assert_eq!(part_two(&example_data, 5), 525152);
+    assert_eq!(part_two(&example_data, 1), 21);
+}
+
+#[test]
+fn test_count_arrangements() {
+    let counts = parse(include_str!("example"))
+        .unwrap()
+        .iter()
+        .map(|record| count_arrangements::<u64>(&record.data, &record.check))
+        .collect::<Vec<_>>();
+    assert_eq!(counts, [1, 1, 4, 4, 1, 10]);
+
+    // Nothing unknown, a single arrangement if the groups match.
+    let clean = parse(include_str!("example_clean")).unwrap();
+    assert_eq!(part_one(&clean), 6);
+
+    let record = &parse("?###???????? 3,2,1\n").unwrap()[0];
+    assert_eq!(
+        count_arrangements::<u128>(&record.unfold(5).data, &record.unfold(5).check),
+        506250
+    );
+    assert_eq!(count_arrangements::<u64>(&[], &[]), 1);
+    assert_eq!(count_arrangements::<u64>(&[], &[1]), 0);
+    assert_eq!(count_arrangements::<u64>(&[Condition::Unknown], &[2]), 0);
+}
+
+/// Sum of the number of arrangements of every record.
+pub fn part_one(data: &[Record]) -> u64 {
+    data.iter()
+        .map(|record| {
+            let count = count_arrangements::<u64>(&record.data, &record.check);
+            trace!("There are {} possible answers.", count);
+            count
+        })
+        .sum()
+}
+
+/// Sum of the number of arrangements of every record unfolded `factor` times.
+pub fn part_two(data: &[Record], factor: usize) -> u128 {
+    data.iter()
+        .map(|record| {
+            let unfolded = record.unfold(factor);
+            count_arrangements::<u128>(&unfolded.data, &unfolded.check)
+        })
+        .sum()
+}
+
+/// Ways to make the unknown springs operational or damaged so the groups of damaged ones have the sizes in `groups`.
+///
+/// Goes along the springs counting the ways to reach every [`State`]. `N` is the type of the counts, e.g. `u64`, or
+/// `u128` for unfolded records.
+pub fn count_arrangements<N>(springs: &[Condition], groups: &[u32]) -> N
+where
+    N: Copy + Default + Add<Output = N> + From<u8>,
+{
+    let groups = groups.iter().map(|&g| g as usize).collect::<Vec<_>>();
+
+    let mut ways = states(&groups, N::default());
+    ways[0][0] = N::from(1);
+    for &spring in springs {
+        let mut next = states(&groups, N::default());
+        for (done, runs) in ways.iter().enumerate() {
+            for (run, &count) in runs.iter().enumerate() {
+                for &choice in choices(spring) {
+                    if let Some((done, run)) = step(&groups, (done, run), choice) {
+                        next[done][run] = next[done][run] + count;
+                    }
+                }
+            }
+        }
+        ways = next;
+    }
+
+    let mut total = N::default();
+    for (done, runs) in ways.iter().enumerate() {
+        for (run, &count) in runs.iter().enumerate() {
+            if is_finished(&groups, (done, run)) {
+                total = total + count;
+            }
+        }
+    }
+
+    total
+}
+
+/// How far along the groups an arrangement is: groups done so far and the length of the run of damaged springs in
+/// progress.
+pub type State = (usize, usize);
+
+/// The state after one more spring, `None` if the spring doesn't fit the groups.
+fn step(groups: &[usize], (done, run): State, spring: Condition) -> Option<State> {
+    match spring {
+        Condition::Operational if run == 0 => Some((done, 0)),
+        Condition::Operational if run == groups[done] => Some((done + 1, 0)),
+        Condition::Damaged if done < groups.len() && run < groups[done] => Some((done, run + 1)),
+        _ => None,
+    }
+}
+
+/// Whether every group is done once the springs end.
+fn is_finished(groups: &[usize], state: State) -> bool {
+    step(groups, state, Condition::Operational) == Some((groups.len(), 0))
+}
+
+/// What a spring can be.
+fn choices(spring: Condition) -> &'static [Condition] {
+    match spring {
+        Condition::Operational => &[Condition::Operational],
+        Condition::Damaged => &[Condition::Damaged],
+        Condition::Unknown => &[Condition::Operational, Condition::Damaged],
+    }
+}
+
+/// A `value` per state, indexed by groups done and then run length.
+fn states<T: Clone>(groups: &[usize], value: T) -> Vec<Vec<T>> {
+    let mut states = groups
+        .iter()
+        .map(|&size| vec![value.clone(); size + 1])
+        .collect::<Vec<_>>();
+    states.push(vec![value]);
+    states
+}
+
+/// `finishable[i][done][run]`: whether the springs from `i` on can finish the groups from that state.
+fn finishable(springs: &[Condition], groups: &[usize]) -> Vec<Vec<Vec<bool>>> {
+    let mut end = states(groups, false);
+    for (done, runs) in end.iter_mut().enumerate() {
+        for (run, finished) in runs.iter_mut().enumerate() {
+            *finished = is_finished(groups, (done, run));
+        }
+    }
+
+    let mut table = vec![end];
+    for &spring in springs.iter().rev() {
+        let after = table.last().unwrap();
+        let mut before = states(groups, false);
+        for (done, runs) in before.iter_mut().enumerate() {
+            for (run, possible) in runs.iter_mut().enumerate() {
+                *possible = choices(spring).iter().any(|&choice| {
+                    step(groups, (done, run), choice).is_some_and(|(d, r)| after[d][r])
+                });
+            }
+        }
+        table.push(before);
+    }
+
+    table.reverse();
+    table
+}
+
+/// Every valid arrangement of a record, made one at a time with operational springs tried first. Only choices that
+/// can still finish the groups are followed, so each arrangement takes a walk along the springs.
+pub struct Arrangements {
+    springs: Vec<Condition>,
+    groups: Vec<usize>,
+    finishable: Vec<Vec<Vec<bool>>>,
+    /// Partial arrangements left to complete and the state after them.
+    stack: Vec<(Vec<Condition>, State)>,
+}
+
+impl Arrangements {
+    pub fn new(record: &Record) -> Arrangements {
+        let groups = record.check.iter().map(|&g| g as usize).collect::<Vec<_>>();
+        let finishable = finishable(&record.data, &groups);
+        let stack = if finishable[0][0][0] {
+            vec![(Vec::new(), (0, 0))]
+        } else {
+            Vec::new()
+        };
+
+        Arrangements {
+            springs: record.data.clone(),
+            groups,
+            finishable,
+            stack,
+        }
+    }
+}
+
+impl Iterator for Arrangements {
+    type Item = Vec<Condition>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((springs, state)) = self.stack.pop() {
+            let i = springs.len();
+            if i == self.springs.len() {
+                return Some(springs);
+            }
+
+            for &choice in choices(self.springs[i]).iter().rev() {
+                if let Some((done, run)) = step(&self.groups, state, choice) {
+                    if self.finishable[i + 1][done][run] {
+                        let mut next = springs.clone();
+                        next.push(choice);
+                        self.stack.push((next, (done, run)));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// The record's springs and groups, then every arrangement on its own line under the springs.
+pub fn render(record: &Record, arrangements: impl IntoIterator<Item = Vec<Condition>>) -> String {
+    let groups = record
+        .check
+        .iter()
+        .map(|g| g.to_string())
+        .collect::<Vec<_>>();
+
+    let mut text = format!("{} {}\n", symbols(&record.data), groups.join(","));
+    for arrangement in arrangements {
+        text.push_str(&symbols(&arrangement));
+        text.push('\n');
+    }
+
+    text
+}
+
+fn symbols(springs: &[Condition]) -> String {
+    springs.iter().map(|s| s.symbol()).collect()
+}
+
+/// The unknown springs that are the same in every valid arrangement, by index, and what they are. `None` if there's no
+/// valid arrangement.
+///
+/// A spring can be damaged if some state reachable before it steps to a state that can still finish the groups, the
+/// same goes for operational.
+pub fn forced(record: &Record) -> Option<Vec<(usize, Condition)>> {
+    let groups = record.check.iter().map(|&g| g as usize).collect::<Vec<_>>();
+    let finishable = finishable(&record.data, &groups);
+    if !finishable[0][0][0] {
+        return None;
+    }
+
+    let mut reachable = states(&groups, false);
+    reachable[0][0] = true;
+
+    let mut forced = Vec::new();
+    for (i, &spring) in record.data.iter().enumerate() {
+        let mut next = states(&groups, false);
+        let mut possible = Vec::new();
+        for (done, runs) in reachable.iter().enumerate() {
+            for (run, &reached) in runs.iter().enumerate() {
+                if !reached {
+                    continue;
+                }
+
+                for &choice in choices(spring) {
+                    let Some((d, r)) = step(&groups, (done, run), choice) else {
+                        continue;
+                    };
+                    if finishable[i + 1][d][r] {
+                        next[d][r] = true;
+                        if !possible.contains(&choice) {
+                            possible.push(choice);
+                        }
+                    }
+                }
+            }
+        }
+
+        if let (Condition::Unknown, [only]) = (spring, &possible[..]) {
+            forced.push((i, *only));
+        }
+        reachable = next;
+    }
+
+    Some(forced)
+}
+
+/// State of a spring.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Condition {
+    /// `#` is damaged, `.` operational and `?` unknown.
+    pub fn parse(symbol: char) -> Option<Self> {
+        match symbol {
+            '#' => Some(Condition::Damaged),
+            '.' => Some(Condition::Operational),
+            '?' => Some(Condition::Unknown),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Condition::Damaged => '#',
+            Condition::Operational => '.',
+            Condition::Unknown => '?',
+        }
+    }
+}
+
+/// A row of springs and the sizes of its groups of damaged springs.
+pub struct Record {
+    pub data: Vec<Condition>,
+    pub check: Vec<u32>,
+}
+
+impl Record {
+    /// Every valid arrangement, lazily.
+    pub fn arrangements(&self) -> Arrangements {
+        Arrangements::new(self)
+    }
+
+    /// The springs `factor` times separated by unknown springs, and the groups `factor` times.
+    pub fn unfold(&self, factor: usize) -> Record {
+        let mut data = Vec::new();
+        for i in 0..factor {
+            if i > 0 {
+                data.push(Condition::Unknown);
+            }
+            data.extend_from_slice(&self.data);
+        }
+
+        Record {
+            data,
+            check: self.check.repeat(factor),
+        }
+    }
+}
+
+/// A record per line, like `???.### 1,1,3`.
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    let mut entries = Vec::new();
+
+    for line in input.lines() {
+        let (data, checksum) = parse::split_once(input, line, " ")?;
+
+        let mut conditions = Vec::new();
+        for (i, symbol) in data.char_indices() {
+            let condition = Condition::parse(symbol).ok_or_else(|| {
+                let found = &data[i..i + symbol.len_utf8()];
+                ParseError::new(input, found, "`#`, `.` or `?`")
+            })?;
+            conditions.push(condition);
+        }
+
+        let checksum = checksum
+            .split(',')
+            .map(|x| parse::number::<u32>(input, x))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        entries.push(Record {
+            data: conditions,
+            check: checksum,
+        });
+    }
+
+    Ok(entries)
+}
+
+#[test]
+fn test_arrangements() {
+    let records = parse(include_str!("example")).unwrap();
+    for record in &records {
+        let count = count_arrangements::<u64>(&record.data, &record.check);
+        assert_eq!(record.arrangements().count() as u64, count);
+    }
+
+    let expected = "\
+.??..??...?##. 1,1,3
+..#...#...###.
+..#..#....###.
+.#....#...###.
+.#...#....###.
+";
+    assert_eq!(render(&records[3], records[3].arrangements()), expected);
+
+    let record = &parse("?#?#?#?#?#?#?#? 1,3,1,6\n").unwrap()[0];
+    let arrangement = record.arrangements().next().unwrap();
+    assert_eq!(symbols(&arrangement), ".#.###.#.######");
+
+    let impossible = &parse("??? 2,1\n").unwrap()[0];
+    assert_eq!(impossible.arrangements().next(), None);
+    assert_eq!(forced(impossible), None);
+}
+
+#[test]
+fn test_forced() {
+    let record = &parse("?###???????? 3,2,1\n").unwrap()[0];
+    assert_eq!(
+        forced(record),
+        Some(vec![
+            (0, Condition::Operational),
+            (4, Condition::Operational)
+        ])
+    );
+
+    // Every unknown spring is forced when there's a single arrangement.
+    let record = &parse("???.### 1,1,3\n").unwrap()[0];
+    assert_eq!(
+        forced(record),
+        Some(vec![
+            (0, Condition::Damaged),
+            (1, Condition::Operational),
+            (2, Condition::Damaged)
+        ])
+    );
+
+    let record = &parse(".??..??...?##. 1,1,3\n").unwrap()[0];
+    assert_eq!(forced(record), Some(vec![(10, Condition::Damaged)]));
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("???.### 1,1,3\n.??..??...?##. 1,x,3\n")
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 18: expected a number, found `x`"
+    );
+
+    let error = parse("??*.### 1,1,3\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 3));
+}
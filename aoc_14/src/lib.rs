@@ -0,0 +1,125 @@
+//! Day 14: Parabolic Reflector Dish, round rocks rolling on a tilting platform.
+
+use std::collections::HashMap;
+
+use common::{parse::ParseError, Solver};
+use grid::{Grid, Position};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 14;
+    type Data = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// The platform, `O` are round rocks, `#` square ones.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Roll every round rock up its column until it hits the edge, a square rock or another round rock.
+fn move_all_up(map: &mut Grid<char>) {
+    for col in 0..map.width() {
+        let mut free_row = 0;
+        for row in 0..map.height() {
+            let pos = Position::new(row, col);
+            match map[pos] {
+                'O' => {
+                    map[pos] = '.';
+                    map[Position::new(free_row, col)] = 'O';
+                    free_row += 1;
+                }
+                '#' => free_row = row + 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Tilt north, west, south and east, turning the platform so whichever side is next faces north.
+fn cycle(map: &mut Grid<char>) {
+    for _ in 0..4 {
+        move_all_up(map);
+        *map = map.rotate_clockwise();
+    }
+}
+
+/// Load on the north support beams, each round rock weighs its distance to the south edge.
+pub fn count_load(map: &Grid<char>) -> usize {
+    let line_count = map.height();
+    let mut sum = 0;
+    for (i, line) in map.rows().enumerate() {
+        let count = line.iter().filter(|x| **x == 'O').count();
+        sum += count * (line_count - i);
+    }
+
+    sum
+}
+
+/// Load after tilting north.
+pub fn part_one(map: &Grid<char>) -> usize {
+    let mut map = map.clone();
+
+    move_all_up(&mut map);
+
+    count_load(&map)
+}
+
+/// Load after a billion spin cycles.
+pub fn part_two(map: &Grid<char>) -> usize {
+    let mut map = map.clone();
+
+    let cycles = 1000000000;
+    let mut seen = HashMap::new();
+
+    let mut i = 0;
+    while i < cycles {
+        cycle(&mut map);
+
+        let load = count_load(&map);
+        trace!("[{}] Load: {}", i, load);
+        i += 1;
+
+        // The rocks end up going round in a loop, once a layout repeats skip ahead by whole loops.
+        if let Some(previous) = seen.insert(map.clone(), i) {
+            let period = i - previous;
+            i += (cycles - i) / period * period;
+        }
+    }
+
+    count_load(&map)
+}
+
+#[test]
+fn test_part_one() {
+    let example = Grid::parse(include_str!("example")).unwrap();
+    let input = Grid::parse(include_str!("input")).unwrap();
+
+    let sum = part_one(&example);
+    assert_eq!(sum, 136);
+
+    let sum = part_one(&input);
+    assert_eq!(sum, 107430);
+}
+
+#[test]
+fn test_part_two() {
+    let example = Grid::parse(include_str!("example")).unwrap();
+
+    let sum = part_two(&example);
+    assert_eq!(sum, 64);
+}
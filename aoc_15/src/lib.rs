@@ -0,0 +1,180 @@
+//! Day 15: Lens Library, the HASH algorithm and lenses in boxes.
+
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 15;
+    type Data = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// Sum of the hashes of the steps.
+pub fn part_one(steps: &[Step]) -> u32 {
+    steps.iter().map(|step| hash(&step.text)).sum()
+}
+
+/// The HASH algorithm, a number from 0 to 255.
+pub fn hash(string: &str) -> u32 {
+    let mut hash = 0;
+
+    for char in string.chars() {
+        let ascii: u32 = char.into();
+        hash += ascii;
+        hash *= 17;
+        hash %= 256;
+    }
+
+    assert!(hash <= u8::MAX.into());
+    trace!("Hashing: {}. Hash: {}", string, hash);
+
+    hash
+}
+
+#[derive(Debug)]
+pub enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+/// One step of the initialization sequence, like `rn=1` or `cm-`.
+pub struct Step {
+    pub text: String,
+    /// Names the lens, its hash is the box it goes in.
+    pub label: String,
+    pub operation: Operation,
+}
+
+/// Steps separated by commas.
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    let mut steps = Vec::new();
+
+    for value in input.split(',').map(|x| x.trim()) {
+        let i = value
+            .find(['=', '-'])
+            .ok_or_else(|| ParseError::new(input, value, "`=` or `-`"))?;
+        let (label, data) = (&value[..i], &value[i + 1..]);
+
+        let operation = if value[i..].starts_with('=') {
+            Operation::Insert(parse::number(input, data)?)
+        } else if data.is_empty() {
+            Operation::Remove
+        } else {
+            return Err(ParseError::new(input, data, "`,` after `-`"));
+        };
+
+        steps.push(Step {
+            text: value.to_string(),
+            label: label.to_string(),
+            operation,
+        });
+    }
+
+    Ok(steps)
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct Lens {
+    label: String,
+    focal_length: u8,
+}
+
+/// Total focusing power of the lenses once every step is done.
+pub fn part_two(steps: &[Step]) -> u32 {
+    let mut hashmap: HashMap<u32, Vec<Lens>> = HashMap::new();
+
+    for step in steps {
+        let label = &step.label;
+        trace!("Label: {}, Operation: {:?}", label, step.operation);
+
+        let box_id = hash(label.as_str());
+
+        match step.operation {
+            Operation::Remove => {
+                if let Some(list) = hashmap.get_mut(&box_id) {
+                    list.retain(|x| x.label != *label)
+                }
+            }
+            Operation::Insert(focal_length) => {
+                let lens = Lens {
+                    label: label.clone(),
+                    focal_length,
+                };
+
+                match hashmap.get_mut(&box_id) {
+                    Some(list) => {
+                        let position = list.iter().position(|x| x.label == *label);
+
+                        match position {
+                            Some(position) => {
+                                list.remove(position);
+                                list.insert(position, lens);
+                            }
+                            None => list.push(lens),
+                        }
+                    }
+                    None => {
+                        hashmap.insert(box_id, vec![lens]);
+                    }
+                };
+            }
+        }
+
+        trace!("Hashmap: {:?}", hashmap);
+    }
+
+    let mut sum: u32 = 0;
+
+    for key in hashmap.keys() {
+        let value = &hashmap[key];
+
+        let box_mult = key + 1;
+        for (i, lens) in value.iter().enumerate() {
+            sum += box_mult * ((i as u32) + 1) * lens.focal_length as u32;
+        }
+    }
+
+    sum
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+    let sum = part_one(&example);
+
+    assert_eq!(sum, 1320);
+
+    let sum = part_two(&example);
+    assert_eq!(sum, 145);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("rn=1,cm-,qp=x,cm-").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 13: expected a number, found `x`"
+    );
+
+    let error = parse("rn=1,cm").err().unwrap();
+    assert_eq!((error.column, error.expected.as_str()), (6, "`=` or `-`"));
+}
@@ -0,0 +1,188 @@
+//! Day 16: The Floor Will Be Lava, beams of light bouncing off mirrors and splitters.
+
+use common::{parse::ParseError, Solver};
+use grid::{Direction, Grid, Position};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 16;
+    type Data = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// The contraption, `.` is empty space, `/` and `\\` mirrors, `|` and `-` splitters.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// The front of a beam of light.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Beam {
+    pub pos: Position,
+    pub facing: Direction,
+}
+
+fn to_bitflag(dir: Direction) -> u8 {
+    match dir {
+        Direction::Right => status::RIGHT,
+        Direction::Left => status::LEFT,
+        Direction::Up => status::UP,
+        Direction::Down => status::DOWN,
+    }
+}
+
+mod status {
+    pub const UNVISITED: u8 = 0x00;
+    pub const RIGHT: u8 = 0x01;
+    pub const LEFT: u8 = 0x02;
+    pub const UP: u8 = 0x04;
+    pub const DOWN: u8 = 0x08;
+}
+
+/// Directions a beam leaves `tile` in when it enters it facing `facing`.
+fn outgoing(tile: char, facing: Direction) -> Vec<Direction> {
+    match (tile, facing) {
+        ('.', _) => vec![facing],
+        ('|', Direction::Right | Direction::Left) => vec![Direction::Up, Direction::Down],
+        ('|', _) => vec![facing],
+        ('-', Direction::Up | Direction::Down) => vec![Direction::Left, Direction::Right],
+        ('-', _) => vec![facing],
+        ('\\', Direction::Right) => vec![Direction::Down],
+        ('\\', Direction::Left) => vec![Direction::Up],
+        ('\\', Direction::Up) => vec![Direction::Left],
+        ('\\', Direction::Down) => vec![Direction::Right],
+        ('/', Direction::Right) => vec![Direction::Up],
+        ('/', Direction::Left) => vec![Direction::Down],
+        ('/', Direction::Up) => vec![Direction::Right],
+        ('/', Direction::Down) => vec![Direction::Left],
+        _ => panic!(),
+    }
+}
+
+/// Tiles energized by a beam entering the top left corner heading right.
+pub fn part_one(map: &Grid<char>) -> usize {
+    let starting_pos = Beam {
+        pos: Position::new(0, 0),
+        facing: Direction::Right,
+    };
+
+    count_energized_from_pos(starting_pos, map)
+}
+
+/// Most tiles energized by a beam entering from any edge tile.
+pub fn part_two(map: &Grid<char>) -> usize {
+    let mut starting_positions = Vec::new();
+
+    // Top and bottom edges
+    for col in 0..map.width() {
+        starting_positions.push(Beam {
+            pos: Position::new(0, col),
+            facing: Direction::Down,
+        });
+        starting_positions.push(Beam {
+            pos: Position::new(map.height() - 1, col),
+            facing: Direction::Up,
+        });
+    }
+
+    // Left and right edges
+    for row in 0..map.height() {
+        starting_positions.push(Beam {
+            pos: Position::new(row, 0),
+            facing: Direction::Right,
+        });
+        starting_positions.push(Beam {
+            pos: Position::new(row, map.width() - 1),
+            facing: Direction::Left,
+        });
+    }
+
+    starting_positions
+        .into_iter()
+        .map(|starting_pos| count_energized_from_pos(starting_pos, map))
+        .max()
+        .unwrap()
+}
+
+/// Tiles the beam passes through, starting with its own.
+pub fn count_energized_from_pos(starting_pos: Beam, map: &Grid<char>) -> usize {
+    let mut energized = Grid::new(map.width(), map.height(), status::UNVISITED);
+
+    let mut current_positions = vec![starting_pos];
+
+    while take_step(&mut current_positions, &mut energized, map) {}
+
+    let count = energized
+        .iter()
+        .filter(|(_, &status)| status != status::UNVISITED)
+        .count();
+
+    count
+}
+
+/// Take a step in the map, if all the current positions have been visited before (in the same direction), return false.
+fn take_step(
+    current_positions: &mut Vec<Beam>,
+    energized: &mut Grid<u8>,
+    map: &Grid<char>,
+) -> bool {
+    // Filter out dead-end positions.
+    current_positions.retain(|beam| {
+        let facing_dir = to_bitflag(beam.facing);
+        let tile_status = &mut energized[beam.pos];
+
+        // If this tile has been visited from the SAME direction, stop.
+        let seen = *tile_status & facing_dir != 0;
+        *tile_status |= facing_dir;
+
+        !seen
+    });
+
+    // Advance to the next positions, beams leaving the map are gone.
+    let new_positions = current_positions
+        .iter()
+        .flat_map(|beam| {
+            outgoing(map[beam.pos], beam.facing)
+                .into_iter()
+                .filter_map(|facing| {
+                    let pos = map.step(beam.pos, facing)?;
+                    Some(Beam { pos, facing })
+                })
+        })
+        .collect();
+
+    *current_positions = new_positions;
+
+    !current_positions.is_empty()
+}
+
+#[test]
+fn test() {
+    let example = Grid::parse(include_str!("example")).unwrap();
+    let input = Grid::parse(include_str!("input")).unwrap();
+
+    let answer = part_one(&example);
+    assert_eq!(answer, 46);
+
+    let answer = part_one(&input);
+    assert_eq!(answer, 6994);
+
+    let answer = part_two(&example);
+    assert_eq!(answer, 51);
+
+    let answer = part_two(&input);
+    assert_eq!(answer, 7488);
+}
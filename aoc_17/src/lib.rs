@@ -0,0 +1,187 @@
+//! Day 17: Clumsy Crucible, the path through the city losing the least heat.
+
+use common::{parse::ParseError, Solver};
+use grid::{Direction, Grid, Position};
+use search::Path;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 17;
+    type Data = Vec<Node>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+}
+
+/// Least heat lost going from the top left to the bottom right block.
+pub fn part_one(graph: &[Node]) -> u32 {
+    let start = graph.first().unwrap();
+    let goal = graph.last().unwrap();
+
+    find_path(graph, start, goal).unwrap().cost
+}
+
+#[test]
+fn test_example() {
+    let example = include_str!("example");
+    let example_graph = parse(example).unwrap();
+
+    let cost = part_one(&example_graph);
+    assert_eq!(cost, 102);
+
+    let path = find_path(&example_graph, &example_graph[0], &example_graph[1]).unwrap();
+    assert_eq!(path.cost, 4);
+    assert_eq!(path.states.len(), 2);
+}
+
+#[test]
+fn test_blocks_without_loss() {
+    // Pseudo-random maps of 0s and 1s, where the plain distance to the goal would overestimate.
+    let mut seed = 17u32;
+    for _ in 0..50 {
+        let map = (0..6)
+            .map(|_| {
+                (0..6)
+                    .map(|_| {
+                        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                        if seed >> 16 & 1 == 0 {
+                            '0'
+                        } else {
+                            '1'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let graph = parse(&map).unwrap();
+
+        let start = Crucible {
+            node: 0,
+            direction: None,
+            straight: 0,
+        };
+        let expected = search::dijkstra(
+            start,
+            |crucible| crucible.moves(&graph),
+            |crucible| crucible.node == graph.len() - 1,
+        )
+        .unwrap();
+        assert_eq!(part_one(&graph), expected.cost, "{}", map);
+    }
+}
+
+#[test]
+fn test_real() {
+    let input = include_str!("input");
+    let input_graph = parse(input).unwrap();
+
+    let cost = part_one(&input_graph);
+    assert_eq!(cost, 1263);
+}
+
+/// Where a crucible is and how it got there, which limits where it can go next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crucible {
+    /// Index of the node it's on.
+    pub node: usize,
+    /// Direction of the last move, `None` before the first one.
+    pub direction: Option<Direction>,
+    /// Moves made in `direction` so far.
+    pub straight: u32,
+}
+
+impl Crucible {
+    /// Blocks it can move in one direction before it has to turn.
+    const MAX_STRAIGHT: u32 = 3;
+
+    /// Moves it can make and the heat lost entering the next block, it can't reverse or go straight for too long.
+    fn moves<'a>(&self, graph: &'a [Node]) -> impl Iterator<Item = (Crucible, u32)> + 'a {
+        let current = *self;
+
+        graph[current.node]
+            .children
+            .iter()
+            .filter_map(move |&(dir, child)| {
+                if current.direction == Some(dir.opposite()) {
+                    return None;
+                }
+
+                let straight = match current.direction {
+                    Some(direction) if direction == dir => current.straight + 1,
+                    _ => 1,
+                };
+                if straight > Crucible::MAX_STRAIGHT {
+                    return None;
+                }
+
+                let next = Crucible {
+                    node: child,
+                    direction: Some(dir),
+                    straight,
+                };
+                Some((next, graph[child].heat_loss as u32))
+            })
+    }
+}
+
+/// Least heat lost going from `start` to `goal` with a crucible that can't go straight for more than three blocks.
+///
+/// The path holds the crucible at every block, `None` when the goal can't be reached. Every block still to enter
+/// loses at least as much heat as the best block of the city, so the distance to the goal times that never
+/// overestimates, and a city with a block that loses nothing is searched like Dijkstra.
+pub fn find_path(graph: &[Node], start: &Node, goal: &Node) -> Option<Path<Crucible, u32>> {
+    let start = Crucible {
+        node: graph.iter().position(|n| n == start)?,
+        direction: None,
+        straight: 0,
+    };
+    let least_loss = graph.iter().map(|n| n.heat_loss as u32).min().unwrap_or(0);
+
+    search::a_star(
+        start,
+        |crucible| crucible.moves(graph),
+        |crucible| graph[crucible.node].pos.manhattan_distance(&goal.pos) as u32 * least_loss,
+        |crucible| graph[crucible.node] == *goal,
+    )
+}
+
+/// A city block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Node {
+    pub pos: Position,
+    pub heat_loss: u8,
+    /// Neighbouring nodes and the direction they are in.
+    pub children: Vec<(Direction, usize)>,
+}
+
+/// A node per digit of the map, row by row.
+pub fn parse(input: &str) -> Result<Vec<Node>, ParseError> {
+    let map = Grid::parse_with(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))?;
+    let index = |pos: Position| pos.row * map.width() + pos.col;
+
+    let nodes = map
+        .iter()
+        .map(|(pos, &heat_loss)| {
+            let children = Direction::ALL
+                .into_iter()
+                .filter_map(|dir| Some((dir, index(map.step(pos, dir)?))))
+                .collect();
+
+            Node {
+                pos,
+                heat_loss,
+                children,
+            }
+        })
+        .collect();
+
+    Ok(nodes)
+}
@@ -0,0 +1,312 @@
+//! Day 18: Lavaduct Lagoon, the area of a lagoon dug along a plan.
+
+use std::str::FromStr;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::{debug, trace};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 18;
+    type Data = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+/// Dig `steps` meters towards `dir`.
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub dir: Direction,
+    pub steps: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dir = match s {
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            _ => return Err(ParseError::new(s, s, "one of `R`, `D`, `L` or `U`")),
+        };
+
+        Ok(dir)
+    }
+}
+
+/// The dig plan read both ways, once as written and once from the colors.
+pub struct DigPlan {
+    pub part_one: Vec<Instruction>,
+    pub part_two: Vec<Instruction>,
+}
+
+#[test]
+fn test() {
+    let example_input = parse(include_str!("example")).unwrap();
+    let test_input = parse(include_str!("test")).unwrap();
+    let input_input = parse(include_str!("input")).unwrap();
+
+    let area = part_one(&example_input);
+    assert_eq!(area, 62);
+
+    let area = part_one(&test_input);
+    assert_eq!(area, 44);
+
+    let area = part_one(&input_input);
+    assert_eq!(area, 28911);
+}
+
+/// Cubic meters of lava the lagoon dug by the written instructions holds.
+pub fn part_one(plan: &DigPlan) -> usize {
+    let perimeter = get_perimeter(&plan.part_one);
+
+    calculate_area(perimeter)
+}
+
+/// Like [`part_one`], digging by the instructions hidden in the colors.
+pub fn part_two(plan: &DigPlan) -> usize {
+    let perimeter = get_perimeter(&plan.part_two);
+
+    calculate_area(perimeter)
+}
+
+#[allow(clippy::needless_range_loop)]
+fn calculate_area(perimeter: Vec<Position>) -> usize {
+    let max_x = perimeter.iter().max_by(|&a, &b| a.x.cmp(&b.x)).unwrap();
+    let max_y = perimeter.iter().max_by(|&a, &b| a.y.cmp(&b.y)).unwrap();
+
+    let min_x = perimeter.iter().min_by(|&a, &b| a.x.cmp(&b.x)).unwrap();
+    let min_y = perimeter.iter().min_by(|&a, &b| a.y.cmp(&b.y)).unwrap();
+
+    let width = max_x.x - min_x.x + 1;
+    let height = max_y.y - min_y.y + 1;
+
+    debug!("Size of map: {{w: {}, h: {}}}", width, height);
+
+    let mut map = vec![vec![None::<&Position>; height as usize]; width as usize];
+
+    debug!("Min x: {}, Max x: {}", min_x.x, max_x.x);
+    debug!("Min y: {}, Max y: {}", min_y.y, max_y.y);
+
+    for node in perimeter.iter() {
+        let x = node.x - min_x.x;
+        let y = node.y - min_y.y;
+
+        map[x as usize][y as usize] = Some(node);
+    }
+
+    let mut in_x = 0;
+    let mut in_y = 0;
+    'out: for y in 0..(height as usize) {
+        let mut inside = false;
+        let mut in_wall = false;
+
+        for x in 0..(width as usize) {
+            let value = map[x][y];
+
+            if value.is_some() {
+                if !in_wall {
+                    inside = !inside;
+                }
+
+                if in_wall {
+                    break;
+                }
+
+                in_wall = true;
+                continue;
+            }
+
+            in_wall = false;
+
+            if inside {
+                trace!("x: {}, y: {}", x, y);
+                in_x = x;
+                in_y = y;
+                break 'out;
+            }
+        }
+    }
+
+    let mut area = 0;
+    let mut to_visit = vec![(in_x, in_y)];
+    let mut visited = vec![vec![false; height as usize]; width as usize];
+    loop {
+        if to_visit.is_empty() {
+            break;
+        }
+
+        let current = to_visit.remove(0);
+        let x = current.0;
+        let y = current.1;
+
+        if visited[x][y] {
+            continue;
+        }
+
+        visited[x][y] = true;
+        area += 1;
+
+        // take all neighbours that are not visited and are not borders.
+        if !(map[x + 1][y].is_some() || visited[x + 1][y]) {
+            to_visit.push((x + 1, y));
+        }
+        if !(map[x - 1][y].is_some() || visited[x - 1][y]) {
+            to_visit.push((x - 1, y));
+        }
+        if !(map[x][y + 1].is_some() || visited[x][y + 1]) {
+            to_visit.push((x, y + 1));
+        }
+        if !(map[x][y - 1].is_some() || visited[x][y - 1]) {
+            to_visit.push((x, y - 1));
+        }
+    }
+
+    let perimeter_length = perimeter.len();
+    debug!("Inside area = {}", area);
+    debug!("Perimeter length = {}", perimeter_length);
+
+    area + perimeter_length
+}
+
+fn get_perimeter(data: &[Instruction]) -> Vec<Position> {
+    let mut perimeter = Vec::new();
+
+    let mut last_pos = Position { x: 0, y: 0 };
+
+    for entry in data {
+        // let color = entry.color.clone();
+
+        for _i in 0..entry.steps {
+            let new_pos = match entry.dir {
+                Direction::Right => Position {
+                    x: last_pos.x + 1,
+                    y: last_pos.y,
+                },
+                Direction::Down => Position {
+                    x: last_pos.x,
+                    y: last_pos.y + 1,
+                },
+                Direction::Left => Position {
+                    x: last_pos.x - 1,
+                    y: last_pos.y,
+                },
+                Direction::Up => Position {
+                    x: last_pos.x,
+                    y: last_pos.y - 1,
+                },
+            };
+
+            last_pos = new_pos.clone();
+            perimeter.push(new_pos);
+        }
+
+        trace!("{:?}", entry);
+    }
+
+    let before = perimeter.len();
+    perimeter.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    let after = perimeter.len();
+    assert_eq!(before, after);
+
+    perimeter
+}
+
+/// A line like `R 6 (#70c710)`, giving the instruction as written and the one hidden in the color.
+fn read_line(line: &str) -> Result<(Instruction, Instruction), ParseError> {
+    let [dir, count, color] = line.split(' ').collect::<Vec<_>>()[..] else {
+        return Err(ParseError::new(
+            line,
+            line,
+            "`<direction> <steps> (#<color>)`",
+        ));
+    };
+
+    let dir = dir.parse::<Direction>().map_err(|e| e.within(line, dir))?;
+    let count = parse::number::<u32>(line, count)?;
+    let written = Instruction { dir, steps: count };
+
+    let color = parse::strip_prefix(line, color, "(#")?;
+    let color = color
+        .strip_suffix(')')
+        .filter(|color| color.len() == 6 && hex::decode(color).is_ok())
+        .ok_or_else(|| ParseError::new(line, color, "six hex digits and `)`"))?;
+
+    let (count, dir) = color.split_at(5);
+    // 0 means R, 1 means D, 2 means L, and 3 means U.
+    let dir = match dir {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => return Err(ParseError::new(line, dir, "a direction from 0 to 3")),
+    };
+    let count = u32::from_str_radix(count, 16).unwrap();
+    let from_color = Instruction { dir, steps: count };
+
+    trace!("{:?}", from_color);
+
+    Ok((written, from_color))
+}
+
+/// An instruction per line.
+pub fn parse(input: &str) -> Result<DigPlan, ParseError> {
+    let mut plan = DigPlan {
+        part_one: Vec::new(),
+        part_two: Vec::new(),
+    };
+
+    for line in input.lines() {
+        let (written, from_color) = read_line(line).map_err(|e| e.within(input, line))?;
+        plan.part_one.push(written);
+        plan.part_two.push(from_color);
+    }
+
+    Ok(plan)
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("R 6 (#70c710)\nX 5 (#0dc571)\n").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected one of `R`, `D`, `L` or `U`, found `X`"
+    );
+
+    let error = parse("R 6 (#70c71)\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 7));
+
+    let error = parse("R 6 (#70c717)\n").err().unwrap();
+    assert_eq!((error.column, error.found.as_str()), (12, "7"));
+}
@@ -0,0 +1,295 @@
+//! Day 19: Aplenty, machine parts sorted by workflows of rules.
+
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::trace;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 19;
+    type Data = System;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+}
+
+/// A machine part and its ratings.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Part {
+    pub x: i32,
+    pub m: i32,
+    pub a: i32,
+    pub s: i32,
+}
+impl Part {
+    pub fn get(&self, prop: &Property) -> i32 {
+        match prop {
+            Property::X => self.x,
+            Property::M => self.m,
+            Property::A => self.a,
+            Property::S => self.s,
+        }
+    }
+}
+
+/// One of the ratings of a part.
+#[derive(Debug)]
+pub enum Property {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl FromStr for Property {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Property::A),
+            "m" => Ok(Property::M),
+            "s" => Ok(Property::S),
+            "x" => Ok(Property::X),
+            _ => Err(ParseError::new(s, s, "one of `x`, `m`, `a` or `s`")),
+        }
+    }
+}
+
+/// A rule of a workflow, conditions hold the rule applied when they're met.
+#[derive(Debug)]
+pub enum Operation {
+    LessThan(Property, i32, Box<Operation>),
+    MoreThan(Property, i32, Box<Operation>),
+    Reject,
+    Accept,
+    Jump(String),
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    /// A rule like `a<2006:qkq`, or just where to go like `rfg`, `A` or `R`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((operation, function_name)) = s.split_once(':') {
+            let jump_to = match function_name {
+                "A" => Operation::Accept,
+                "R" => Operation::Reject,
+                _ => Operation::Jump(function_name.to_string()),
+            };
+
+            let Some(i) = operation.find(['<', '>']) else {
+                return Err(ParseError::new(s, operation, "`<` or `>`"));
+            };
+            let (prop, value) = (&operation[..i], &operation[i + 1..]);
+            let num = parse::number::<i32>(s, value)?;
+            let prop = prop.parse::<Property>().map_err(|e| e.within(s, prop))?;
+
+            if operation[i..].starts_with('>') {
+                Ok(Operation::MoreThan(prop, num, Box::new(jump_to)))
+            } else {
+                Ok(Operation::LessThan(prop, num, Box::new(jump_to)))
+            }
+        } else {
+            match s {
+                "A" => Ok(Operation::Accept),
+                "R" => Ok(Operation::Reject),
+                _ => Ok(Operation::Jump(s.to_string())),
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+
+    let sum = part_one(&example);
+    assert_eq!(19114, sum);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("in{s<1351:px,qqz}\npx{a<2006:qkq,y>2090:A,rfg}\n")
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 15: expected one of `x`, `m`, `a` or `s`, found `y`"
+    );
+
+    let error = parse("in{s<1351:px,qqz}\n\n{x=787,m=2655,a=1222}\n")
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (3, 1));
+
+    let error = parse("in{x>1:A}\n\n{x=1,m=1,a=1,s=1}\n").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 4: expected a rule without a condition last, found `x>1:A`"
+    );
+
+    let error = parse("in{x>1:A,foo}\nfoo{R}\nbar{baz}\n").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 3, column 5: expected the name of a workflow, found `baz`"
+    );
+
+    let error = parse("px{A}\n\n{x=1,m=1,a=1,s=1}\n").err().unwrap();
+    assert_eq!(error.expected, "a workflow named `in`");
+}
+
+/// Sum of the ratings of every accepted part.
+pub fn part_one(system: &System) -> i32 {
+    let datas = &system.parts;
+    let functions = &system.workflows;
+
+    let mut sum = 0;
+    for data in datas {
+        let entry_point = "in";
+        let mut function = functions[entry_point].iter();
+        let mut operation = function.next().unwrap();
+
+        let accepted: bool = loop {
+            break match operation {
+                Operation::LessThan(prop, num, fun) => {
+                    let value = data.get(prop);
+                    if value < *num {
+                        operation = fun.to_owned();
+                    } else {
+                        operation = function.next().unwrap();
+                    }
+
+                    continue;
+                }
+                Operation::MoreThan(prop, num, fun) => {
+                    let value = data.get(prop);
+                    if value > *num {
+                        operation = fun.to_owned();
+                    } else {
+                        operation = function.next().unwrap();
+                    }
+
+                    continue;
+                }
+                Operation::Reject => false,
+                Operation::Accept => true,
+                Operation::Jump(fun) => {
+                    function = functions[fun.as_str()].iter();
+                    operation = function.next().unwrap();
+                    continue;
+                }
+            };
+        };
+
+        if accepted {
+            trace!("Part accepted: {:?}", data);
+
+            let num = data.a + data.m + data.s + data.x;
+            sum += num;
+        }
+    }
+
+    sum
+}
+
+/// The workflows and the parts to sort with them.
+pub struct System {
+    /// Rules of each workflow by its name.
+    pub workflows: HashMap<String, Vec<Operation>>,
+    pub parts: Vec<Part>,
+}
+
+/// A line like `px{a<2006:qkq,m>2090:A,rfg}`, and the workflows its rules jump to.
+fn read_workflow(line: &str) -> Result<(String, Vec<Operation>, Vec<&str>), ParseError> {
+    let (name, operations) = parse::split_once(line, line, "{")?;
+    let operations = operations
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::new(line, line, "`}` at the end"))?;
+    let rules = operations.split(',').collect::<Vec<_>>();
+    let operations = rules
+        .iter()
+        .map(|op| op.parse::<Operation>().map_err(|e| e.within(line, op)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Parts that don't meet any condition need somewhere to go.
+    if let Some(last) = rules.last().filter(|rule| rule.contains(':')) {
+        return Err(ParseError::new(
+            line,
+            last,
+            "a rule without a condition last",
+        ));
+    }
+
+    let targets = rules
+        .iter()
+        .map(|rule| rule.rsplit_once(':').map_or(*rule, |(_, target)| target))
+        .filter(|target| !["A", "R"].contains(target))
+        .collect();
+
+    trace!("Instruction: {}, {:?}", name, operations);
+
+    Ok((name.to_string(), operations, targets))
+}
+
+/// A line like `{x=787,m=2655,a=1222,s=2876}`.
+fn read_part(line: &str) -> Result<Part, ParseError> {
+    let data = line.replace("=", "\":");
+    let data = data.replace(",", ",\"");
+    let data = data.replace("{", "{\"");
+
+    let data: Part = serde_json::from_str(&data)
+        .map_err(|_| ParseError::new(line, line, "`{x=<n>,m=<n>,a=<n>,s=<n>}`"))?;
+    trace!("Part: {:?}", data);
+
+    Ok(data)
+}
+
+/// The workflows, an empty line, then the parts.
+pub fn parse(input: &str) -> Result<System, ParseError> {
+    let mut datas = Vec::new();
+    let mut functions = HashMap::new();
+    let mut targets = Vec::new();
+
+    let mut reading_data = false;
+    for line in input.lines() {
+        if reading_data {
+            datas.push(read_part(line).map_err(|e| e.within(input, line))?);
+        } else {
+            if line.is_empty() {
+                reading_data = true;
+                continue;
+            }
+
+            let (name, operations, jumps) =
+                read_workflow(line).map_err(|e| e.within(input, line))?;
+            functions.insert(name, operations);
+            targets.extend(jumps);
+        }
+    }
+
+    // Sorting starts at `in` and follows the rules by name.
+    if let Some(missing) = targets.into_iter().find(|t| !functions.contains_key(*t)) {
+        return Err(ParseError::new(input, missing, "the name of a workflow"));
+    }
+    if !functions.contains_key("in") {
+        return Err(ParseError::new(input, "", "a workflow named `in`"));
+    }
+
+    Ok(System {
+        workflows: functions,
+        parts: datas,
+    })
+}
@@ -0,0 +1,406 @@
+//! Day 20: Pulse Propagation, high and low pulses sent through a network of modules.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    ops::AddAssign,
+};
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::{debug, trace};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 20;
+    type Data = Vec<Module>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+}
+
+struct OperationInfo {
+    sender: String,
+    target: String,
+    signal: Signal,
+}
+
+#[test]
+fn test() {
+    let example_a = parse(include_str!("example_a")).unwrap();
+    let output = part_one(&example_a);
+    assert_eq!(output, 32000000);
+
+    let example_b = parse(include_str!("example_b")).unwrap();
+    let output = part_one(&example_b);
+    assert_eq!(output, 11687500);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("broadcaster -> a\n%a -> inv, con\n&inv -> b\n$b -> con\n")
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 4, column 1: expected `broadcaster`, or a name after `%` or `&`, found `$b`"
+    );
+
+    let error = parse("broadcaster a\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.expected, "`->`");
+}
+
+/// Product of the low and high pulses sent when pushing the button 1000 times.
+pub fn part_one(modules: &[Module]) -> u32 {
+    let mut components = build_components(modules);
+
+    let mut total_stats = SignalStats {
+        low_count: 0,
+        high_count: 0,
+    };
+
+    for _ in 0..1000 {
+        let stats = press_button(&mut components);
+        total_stats += stats;
+    }
+
+    debug!("Stats: {:?}", total_stats);
+    total_stats.high_count * total_stats.low_count
+}
+
+#[derive(Debug)]
+struct SignalStats {
+    low_count: u32,
+    high_count: u32,
+}
+
+impl AddAssign for SignalStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.low_count += rhs.low_count;
+        self.high_count += rhs.high_count;
+    }
+}
+
+fn press_button(components: &mut HashMap<String, Box<dyn Component>>) -> SignalStats {
+    trace!("Components: {:?}", components);
+
+    let mut instructions_queue: VecDeque<OperationInfo> = VecDeque::new();
+    instructions_queue.push_back(OperationInfo {
+        sender: "button".to_string(),
+        target: "broadcaster".to_owned(),
+        signal: Signal::Low,
+    });
+
+    let mut low_count = 0;
+    let mut high_count = 0;
+
+    while !instructions_queue.is_empty() {
+        let instruction = instructions_queue.pop_front().unwrap();
+
+        match instruction.signal {
+            Signal::High => high_count += 1,
+            Signal::Low => low_count += 1,
+        }
+
+        let component = components.get_mut(&instruction.target);
+        match component {
+            Some(component) => {
+                let next = component.on_input(&instruction.sender, instruction.signal);
+                match next {
+                    Some(next) => {
+                        for name in next.0 {
+                            trace!("Adding to queue: {}, {:?}", name, next.1);
+                            instructions_queue.push_back(OperationInfo {
+                                sender: component.get_name(),
+                                target: name,
+                                signal: next.1,
+                            });
+                        }
+                    }
+                    None => {
+                        trace!("No output from: {}", instruction.target);
+                    }
+                }
+            }
+            None => {
+                trace!(
+                    "No component named: {}. Signal: {:?}",
+                    instruction.target,
+                    instruction.signal
+                );
+            }
+        }
+    }
+
+    SignalStats {
+        low_count,
+        high_count,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+/// A module of the configuration as written, before it's wired up.
+#[derive(Debug)]
+pub struct Module {
+    pub kind: ModuleKind,
+    pub name: String,
+    /// Names of the modules it sends pulses to.
+    pub targets: Vec<String>,
+}
+
+/// A line like `%a -> inv, con`.
+fn read_module(line: &str) -> Result<Module, ParseError> {
+    let (opr, targets) = parse::split_once(line, line, "->")?;
+    let opr = opr.trim();
+    let targets = targets.split(',').map(|t| t.trim().to_string()).collect();
+
+    let (kind, name) = if opr == "broadcaster" {
+        (ModuleKind::Broadcaster, opr)
+    } else if let Some(name) = opr.strip_prefix('%').filter(|n| !n.is_empty()) {
+        (ModuleKind::FlipFlop, name)
+    } else if let Some(name) = opr.strip_prefix('&').filter(|n| !n.is_empty()) {
+        (ModuleKind::Conjunction, name)
+    } else {
+        let expected = "`broadcaster`, or a name after `%` or `&`";
+        return Err(ParseError::new(line, opr, expected));
+    };
+
+    Ok(Module {
+        kind,
+        name: name.to_string(),
+        targets,
+    })
+}
+
+/// A module per line.
+pub fn parse(input: &str) -> Result<Vec<Module>, ParseError> {
+    input
+        .lines()
+        .map(|line| read_module(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+fn build_components(modules: &[Module]) -> HashMap<String, Box<dyn Component>> {
+    let mut operations: HashMap<String, Box<dyn Component>> = HashMap::new();
+    let mut parents: HashMap<String, Vec<String>> = HashMap::new();
+    let mut children = HashMap::new();
+
+    for module in modules {
+        let name = module.name.clone();
+        let targets = &module.targets;
+
+        match module.kind {
+            ModuleKind::Conjunction => {
+                let conj = Conjunction {
+                    name: name.clone(),
+                    children: Vec::new(),
+                    parent: Vec::new(),
+                    memory: HashMap::new(),
+                };
+
+                for target in targets.iter() {
+                    parents
+                        .entry(target.clone())
+                        .or_default()
+                        .push(name.clone());
+                }
+                children.insert(name.clone(), targets);
+                operations.insert(name, Box::new(conj));
+            }
+            ModuleKind::FlipFlop => {
+                // Flip-flop modules (prefix %) are either on or off; they are initially off.
+                let ff = FlipFlop {
+                    name: name.clone(),
+                    children: Vec::new(),
+                    on: false,
+                };
+
+                for target in targets.iter() {
+                    parents
+                        .entry(target.clone())
+                        .or_default()
+                        .push(name.clone());
+                }
+                children.insert(name.clone(), targets);
+                operations.insert(name, Box::new(ff));
+            }
+            ModuleKind::Broadcaster => {
+                let b = Broadcaster {
+                    children: Vec::new(),
+                };
+
+                children.insert(name.clone(), targets);
+                operations.insert(name, Box::new(b));
+            }
+        }
+    }
+
+    // patch children and parents of operations.
+    let mut temp_data = HashMap::new();
+    for key in operations.keys() {
+        let children = children
+            .get(key)
+            .unwrap()
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>();
+
+        let parents = parents.get(key);
+        let parents = match parents {
+            Some(parents) => parents.clone(),
+            None => Vec::new(),
+        };
+
+        temp_data.insert(key.clone(), (parents, children));
+    }
+
+    for key in temp_data.keys() {
+        let operation = operations.get_mut(key).unwrap();
+        let data = temp_data.get(key).unwrap();
+        let parents = &data.0;
+        let children = &data.1;
+
+        for parent in parents {
+            operation.add_parent(parent);
+        }
+        for child in children {
+            operation.add_child(child);
+        }
+    }
+
+    operations
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Signal {
+    High,
+    Low,
+}
+
+trait Component: Debug {
+    fn get_name(&self) -> String;
+    fn add_child(&mut self, child: &str);
+    fn add_parent(&mut self, parent: &str);
+    fn on_input(&mut self, sender: &str, signal: Signal) -> Option<(Vec<String>, Signal)>;
+}
+
+#[derive(Debug)]
+/// If a flip-flop module receives a high pulse, it is ignored and nothing happens. However, if a flip-flop module receives a low pulse, it flips between on and off. If it was off, it turns on and sends a high pulse. If it was on, it turns off and sends a low pulse.
+struct FlipFlop {
+    name: String,
+    on: bool,
+    children: Vec<String>,
+}
+
+impl Component for FlipFlop {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn on_input(&mut self, _: &str, signal: Signal) -> Option<(Vec<String>, Signal)> {
+        match signal {
+            Signal::High => None,
+            Signal::Low => {
+                match self.on {
+                    true => {
+                        // turns off and sends a low pulse.
+                        self.on = false;
+                        Some((self.children.clone(), Signal::Low))
+                    }
+                    false => {
+                        // turns on and sends a high pulse.
+                        self.on = true;
+                        Some((self.children.clone(), Signal::High))
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_child(&mut self, child: &str) {
+        self.children.push(child.to_string());
+    }
+
+    fn add_parent(&mut self, _: &str) {}
+}
+
+/// Conjunction modules (prefix &) remember the type of the most recent pulse received from each of their connected input modules; they initially default to remembering a low pulse for each input. When a pulse is received, the conjunction module first updates its memory for that input. Then, if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
+#[derive(Debug)]
+struct Conjunction {
+    name: String,
+    children: Vec<String>,
+    parent: Vec<String>,
+    memory: HashMap<String, Signal>,
+}
+
+impl Component for Conjunction {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn add_child(&mut self, child: &str) {
+        self.children.push(child.to_string());
+    }
+
+    fn add_parent(&mut self, parent: &str) {
+        self.parent.push(parent.to_string());
+        // They initially default to remembering a low pulse for each input.
+        self.memory.insert(parent.to_string(), Signal::Low);
+    }
+
+    fn on_input(&mut self, sender: &str, signal: Signal) -> Option<(Vec<String>, Signal)> {
+        match self.memory.get(sender) {
+            Some(_) => {
+                // When a pulse is received, the conjunction module first updates its memory for that input.
+                self.memory.insert(sender.to_owned(), signal);
+                // Then, if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
+                let pulse = if self.memory.values().any(|&s| s == Signal::Low) {
+                    Signal::High
+                } else {
+                    Signal::Low
+                };
+                Some((self.children.clone(), pulse))
+            }
+            None => panic!(
+                "Expected to have some memory, was add_parent called? \n Component: {}. Sender: {}. Memory: {:?}",
+                self.name, sender, self.memory
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Broadcaster {
+    children: Vec<String>,
+}
+impl Component for Broadcaster {
+    fn get_name(&self) -> String {
+        "broadcaster".to_string()
+    }
+
+    fn add_child(&mut self, child: &str) {
+        self.children.push(child.to_string());
+    }
+
+    fn add_parent(&mut self, _: &str) {}
+
+    fn on_input(&mut self, _: &str, signal: Signal) -> Option<(Vec<String>, Signal)> {
+        Some((self.children.clone(), signal))
+    }
+}
@@ -0,0 +1,67 @@
+//! Day 21: Step Counter, garden plots an elf can reach in a number of steps.
+
+use std::collections::HashSet;
+
+use common::{parse::ParseError, Solver};
+use grid::Grid;
+use log::debug;
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 21;
+    type Data = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data, 64).to_string())
+    }
+}
+
+/// The garden, `.` are plots, `#` rocks and `S` the starting plot.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+#[test]
+fn test() {
+    let example = Grid::parse(include_str!("example")).unwrap();
+    let count = part_one(&example, 6);
+    assert_eq!(count, 16);
+
+    let input = Grid::parse(include_str!("input")).unwrap();
+    let count = part_one(&input, 64);
+    assert_eq!(count, 3788);
+}
+
+/// Garden plots the elf can be on after exactly `max_steps` steps.
+pub fn part_one(map: &Grid<char>, max_steps: i32) -> usize {
+    let start = map.find(&'S').unwrap();
+    debug!("Starting at: {:?}", start);
+
+    let mut current_positions = HashSet::new();
+    current_positions.insert(start);
+
+    for _ in 0..max_steps {
+        let mut next_positions = Vec::new();
+
+        for value in current_positions.iter() {
+            for pos in map.neighbours(*value) {
+                if map[pos] != '#' {
+                    next_positions.push(pos);
+                }
+            }
+        }
+
+        current_positions.clear();
+        for pos in next_positions {
+            current_positions.insert(pos);
+        }
+    }
+
+    current_positions.len()
+}
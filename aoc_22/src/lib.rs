@@ -0,0 +1,419 @@
+//! Day 22: Sand Slabs, bricks of sand settling and the ones safe to disintegrate.
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    vec,
+};
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+use log::{debug, trace};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 22;
+    type Data = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// A cube of space, `z` is the height.
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+impl FromStr for Point {
+    type Err = ParseError;
+
+    /// Coordinates like `1,0,1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split(',').collect::<Vec<_>>()[..] {
+            [x, y, z] => {
+                let x = parse::number(s, x)?;
+                let y = parse::number(s, y)?;
+                let z = parse::number(s, z)?;
+
+                Ok(Point { x, y, z })
+            }
+            _ => Err(ParseError::new(s, s, "three numbers separated by `,`")),
+        }
+    }
+}
+/// A straight brick from one end to the other, as in the snapshot.
+#[derive(Debug)]
+pub struct Brick {
+    pub from: Point,
+    pub to: Point,
+}
+
+impl Brick {
+    fn get_blocks(&self) -> Vec<Point> {
+        let mut blocks = vec![self.from];
+
+        let x = self.from.x;
+        let y = self.from.y;
+        let z = self.from.z;
+
+        let mut check = false;
+        if self.from.x != self.to.x {
+            assert!(!check);
+            check = true;
+            for x in self.from.x..self.to.x {
+                let x = x + 1;
+                blocks.push(Point { x, y, z });
+            }
+        }
+
+        if self.from.y != self.to.y {
+            assert!(!check);
+            check = true;
+            for y in self.from.y..self.to.y {
+                let y = y + 1;
+                blocks.push(Point { x, y, z });
+            }
+        }
+
+        if self.from.z != self.to.z {
+            assert!(!check);
+            for z in self.from.z..self.to.z {
+                let z = z + 1;
+                blocks.push(Point { x, y, z });
+            }
+        }
+
+        blocks
+    }
+}
+
+impl FromStr for Brick {
+    type Err = ParseError;
+
+    /// Both ends of the brick, like `1,0,1~1,2,1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = parse::split_once(s, s, "~")?;
+        let from = from.parse::<Point>().map_err(|e| e.within(s, from))?;
+        let to = to.parse::<Point>().map_err(|e| e.within(s, to))?;
+
+        {
+            let mut difference_count = 0;
+            if from.x != to.x {
+                difference_count += 1;
+            }
+            if from.y != to.y {
+                difference_count += 1;
+            }
+            if from.z != to.z {
+                difference_count += 1;
+            }
+            // It is assumed that the bricks are straight.
+            if difference_count > 1 {
+                return Err(ParseError::new(s, s, "a straight brick"));
+            }
+        }
+
+        Ok(Brick { from, to })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BrickInstance {
+    index: usize,
+    blocks: Vec<Point>,
+}
+
+impl BrickInstance {
+    fn is_vertical(&self) -> bool {
+        let first = self.blocks.first().unwrap();
+        let different_x_or_y = self.blocks.iter().any(|p| p.x != first.x || p.y != first.y);
+
+        !different_x_or_y
+    }
+
+    fn lowest(&self) -> &Point {
+        self.blocks.iter().min_by(|a, b| a.z.cmp(&b.z)).unwrap()
+    }
+
+    fn highest(&self) -> &Point {
+        self.blocks.iter().max_by(|a, b| a.z.cmp(&b.z)).unwrap()
+    }
+
+    fn fall(&mut self, map: &mut [Vec<Vec<usize>>]) {
+        for p in self.blocks.iter() {
+            map[p.x][p.y][p.z] = 0;
+        }
+        for p in self.blocks.iter_mut() {
+            p.z -= 1;
+            map[p.x][p.y][p.z] = self.index;
+        }
+    }
+
+    fn on_ground(&self) -> bool {
+        self.blocks.iter().any(|p| p.z == 1)
+    }
+
+    fn get_above(&self, map: &[Vec<Vec<usize>>]) -> Vec<usize> {
+        if self.is_vertical() {
+            let highest = self.highest();
+            let mut above_indices = Vec::new();
+            let above = map[highest.x][highest.y].get(highest.z + 1);
+            if let Some(&above) = above {
+                if above != 0 {
+                    above_indices.push(above);
+                }
+            }
+
+            return above_indices;
+        }
+
+        let mut above_indices: Vec<usize> = self
+            .blocks
+            .iter()
+            .map(|p| map[p.x][p.y][p.z + 1])
+            .filter(|&n| n != 0)
+            .collect();
+        above_indices.dedup();
+        above_indices
+    }
+
+    fn get_below(&self, map: &[Vec<Vec<usize>>]) -> Vec<usize> {
+        if self.is_vertical() {
+            let lowest = self.lowest();
+            let mut below_indices = Vec::new();
+            let below = map[lowest.x][lowest.y][lowest.z - 1];
+            if below != 0 {
+                below_indices.push(below);
+            }
+
+            return below_indices;
+        }
+
+        let mut below_indices: Vec<usize> = self
+            .blocks
+            .iter()
+            .map(|p| map[p.x][p.y][p.z - 1])
+            .filter(|&n| n != 0)
+            .collect();
+        below_indices.dedup();
+        below_indices
+    }
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+    let one = part_one(&example);
+    assert_eq!(one, 5);
+
+    let two = part_two(&example);
+    assert_eq!(two, 7);
+
+    let input = parse(include_str!("input")).unwrap();
+    let one = part_one(&input);
+    assert_eq!(one, 501);
+
+    let two = part_two(&input);
+    assert_eq!(two, 80948);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("1,0,1~1,2,1\n0,0,2~2,0,x\n").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 11: expected a number, found `x`"
+    );
+
+    let error = parse("1,0,1~1,2,1\n0,0,2~2,1,2\n").err().unwrap();
+    assert_eq!(
+        (error.line, error.expected.as_str()),
+        (2, "a straight brick")
+    );
+}
+
+/// Bricks that could be disintegrated, once settled, without any other brick falling.
+pub fn part_one(bricks: &[Brick]) -> u32 {
+    let (mut instances, mut map) = build(bricks);
+
+    let fallen_count = do_fall(&mut instances, &mut map, None);
+    debug!("{} blocks have fallen.", fallen_count);
+
+    calculate_can_safely_remove_count(&instances, &map)
+}
+
+/// Sum over every brick of how many others would fall if it was disintegrated.
+pub fn part_two(bricks: &[Brick]) -> usize {
+    let (mut instances, mut map) = build(bricks);
+    do_fall(&mut instances, &mut map, None);
+
+    let mut sum = 0;
+
+    for brick in instances.values() {
+        let mut instances = instances.clone();
+        let mut map = map.clone();
+
+        let fallen_count = do_fall(&mut instances, &mut map, Some(brick.index));
+        sum += fallen_count;
+    }
+
+    sum
+}
+
+/// A brick per line.
+pub fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.parse().map_err(|e: ParseError| e.within(input, line)))
+        .collect()
+}
+
+/// Place the bricks where the snapshot has them, before anything falls.
+fn build(bricks: &[Brick]) -> (HashMap<usize, BrickInstance>, Vec<Vec<Vec<usize>>>) {
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut max_z = 0;
+
+    for brick in bricks.iter() {
+        max_x = std::cmp::max(max_x, brick.from.x);
+        max_x = std::cmp::max(max_x, brick.to.x);
+        max_y = std::cmp::max(max_y, brick.from.y);
+        max_y = std::cmp::max(max_y, brick.to.y);
+        max_z = std::cmp::max(max_z, brick.from.z);
+        max_z = std::cmp::max(max_z, brick.to.z);
+    }
+
+    let mut map = vec![vec![vec![0; max_z + 1]; max_y + 1]; max_x + 1];
+    debug!("Created map with x: {}, y: {}, z: {}", max_x, max_y, max_z);
+
+    let mut instances = HashMap::new();
+
+    for (i, brick) in bricks.iter().enumerate() {
+        let blocks = brick.get_blocks();
+        trace!("Brick: {:?} has blocks: \n{:?}", brick, blocks);
+
+        let index = i + 1;
+        assert!(index != 0);
+
+        for p in blocks.iter() {
+            map[p.x][p.y][p.z] = index;
+        }
+
+        instances.insert(index, BrickInstance { index, blocks });
+    }
+
+    (instances, map)
+}
+
+fn do_fall(
+    instances: &mut HashMap<usize, BrickInstance>,
+    map: &mut [Vec<Vec<usize>>],
+    ignore_index: Option<usize>,
+) -> usize {
+    let mut fallen_bricks = HashSet::new();
+    loop {
+        let mut have_fallen = false;
+        debug!("Starting fall loop");
+        for instance in instances.values_mut() {
+            // make it fall down if possible
+            if instance.on_ground() {
+                trace!("Brick {} is on the ground.", instance.index);
+                continue;
+            }
+
+            if instance.is_vertical() {
+                let lowest = instance.lowest();
+                let index_below = map[lowest.x][lowest.y][lowest.z - 1];
+
+                if is_empty(index_below, ignore_index) {
+                    instance.fall(map);
+                    fallen_bricks.insert(instance.index);
+                    have_fallen = true;
+                } else {
+                    trace!(
+                        "Below vertical block: {} is block: {}",
+                        instance.index,
+                        index_below
+                    );
+                }
+            } else {
+                let cant_fall = instance
+                    .blocks
+                    .iter()
+                    .any(|p| !is_empty(map[p.x][p.y][p.z - 1], ignore_index));
+
+                if cant_fall {
+                    trace!("Brick: {} can't fall.", instance.index);
+                } else {
+                    instance.fall(map);
+                    fallen_bricks.insert(instance.index);
+                    have_fallen = true;
+                }
+            }
+        }
+
+        if !have_fallen {
+            debug!("No more bricks have fallen, ending fall loop.");
+
+            for brick in instances.values() {
+                trace!("Brick: {} is at: {:?}", brick.index, brick.blocks);
+            }
+            break;
+        }
+    }
+
+    fallen_bricks.len()
+}
+
+fn is_empty(value: usize, ignore_index: Option<usize>) -> bool {
+    match ignore_index {
+        Some(ignore_value) => value == 0 || value == ignore_value,
+        None => value == 0,
+    }
+}
+
+fn calculate_can_safely_remove_count(
+    instances: &HashMap<usize, BrickInstance>,
+    map: &[Vec<Vec<usize>>],
+) -> u32 {
+    let mut can_safely_remove_count = 0;
+
+    for brick in instances.values() {
+        // which bricks are we supporting?
+        let supporting = brick.get_above(map);
+        trace!("Brick {} is supporting {:?}", brick.index, supporting);
+
+        let mut can_remove = true;
+        for brick_index in supporting {
+            let supported_by = instances.get(&brick_index).unwrap().get_below(map);
+            trace!("Brick {} is supported by {:?}", brick_index, supported_by);
+
+            assert!(supported_by.contains(&brick.index));
+            if supported_by.len() == 1 {
+                can_remove = false;
+                break;
+            }
+        }
+
+        if can_remove {
+            can_safely_remove_count += 1;
+        }
+    }
+
+    can_safely_remove_count
+}
@@ -0,0 +1,271 @@
+//! Day 23: A Long Walk, the longest hike through a map of trails and slopes.
+
+use std::collections::HashSet;
+
+use common::{parse::ParseError, Solver};
+use grid::{Direction, Grid, Position};
+use log::{debug, trace};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 23;
+    type Data = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data).to_string())
+    }
+
+    fn part_two(data: &Self::Data) -> Option<String> {
+        Some(part_two(data).to_string())
+    }
+}
+
+/// The map, `.` are paths, `#` forest and `^>v<` slopes.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+#[test]
+fn test() {
+    let example = Grid::parse(include_str!("example")).unwrap();
+    let one = part_one(&example);
+    assert_eq!(one, 94);
+
+    let two = part_two(&example);
+    assert_eq!(two, 154);
+
+    let input = Grid::parse(include_str!("input")).unwrap();
+    let one = part_one(&input);
+    assert_eq!(one, 2362);
+}
+
+/// Steps of the longest hike when slopes can be climbed like paths.
+pub fn part_two(map: &Grid<char>) -> usize {
+    let mut cost_map = Grid::new(map.width(), map.height(), 0);
+
+    let (start, goal) = start_and_goal(map);
+
+    let mut routes = vec![Route::new(start)];
+    let mut max_length = 0;
+
+    loop {
+        routes.retain(|route| !route.dead_end && !route.completed);
+        let can_continue = !routes.is_empty();
+
+        if !can_continue {
+            break;
+        }
+
+        let routes_count = routes.len();
+        trace!("Exploring {} different routes.", routes_count);
+
+        let mut new_splits = Vec::new();
+        for route in routes.iter_mut() {
+            route.visited.insert(route.current);
+
+            let current_route_length = route.visited.len() as u32;
+            let current_max_length = cost_map[route.current];
+
+            // println!(
+            //     "Current length: {}. Previous Max Length: {}",
+            //     current_route_length, current_max_length
+            // );
+
+            if current_max_length > current_route_length {
+                route.dead_end = true;
+                continue;
+            }
+
+            cost_map[route.current] = current_route_length;
+
+            if route.completed || route.dead_end {
+                panic!();
+            }
+
+            if route.current == goal {
+                route.completed = true;
+                max_length = std::cmp::max(max_length, route.visited.len() - 1);
+                // println!("One route reached the goal.");
+                continue;
+            }
+
+            let mut next_positions = Vec::new();
+            for pos in map.neighbours(route.current) {
+                let tile = map[pos];
+
+                if route.visited.contains(&pos) {
+                    continue;
+                }
+
+                if tile != '#' {
+                    next_positions.push(pos);
+                }
+            }
+
+            match next_positions.len() {
+                0 => {
+                    route.dead_end = true;
+                }
+                1 => {
+                    route.current = *next_positions.first().unwrap();
+                }
+                _ => {
+                    for (i, pos) in next_positions.iter().enumerate() {
+                        if i == 0 {
+                            route.current = *pos;
+                        } else {
+                            let mut split = route.clone();
+                            split.current = *pos;
+                            new_splits.push(split);
+                        }
+                    }
+                }
+            }
+        }
+
+        for split in new_splits {
+            routes.push(split);
+        }
+    }
+
+    max_length
+}
+
+/// Steps of the longest hike that never steps on a tile twice, going down slopes only.
+pub fn part_one(map: &Grid<char>) -> usize {
+    let (start, goal) = start_and_goal(map);
+
+    debug!("Start: {:?}. Goal: {:?}", start, goal);
+
+    let mut routes = vec![Route::new(start)];
+
+    loop {
+        routes.retain(|route| !route.dead_end);
+        let can_continue = routes.iter().any(|route| !route.completed);
+
+        if !can_continue {
+            break;
+        }
+
+        let mut new_splits = Vec::new();
+        for route in routes.iter_mut() {
+            route.visited.insert(route.current);
+
+            if route.current == goal {
+                route.completed = true;
+                continue;
+            }
+
+            let next_positions = route.get_next_positions(map);
+
+            match next_positions.len() {
+                0 => {
+                    route.dead_end = true;
+                }
+                1 => {
+                    route.current = *next_positions.first().unwrap();
+                }
+                _ => {
+                    for (i, pos) in next_positions.iter().enumerate() {
+                        if i == 0 {
+                            route.current = *pos;
+                        } else {
+                            let mut split = route.clone();
+                            split.current = *pos;
+                            new_splits.push(split);
+                        }
+                    }
+                }
+            }
+        }
+
+        for split in new_splits {
+            routes.push(split);
+        }
+    }
+
+    let mut max_length = 0;
+    for route in routes {
+        let length = route.visited.len() - 1;
+        if route.completed {
+            trace!("Completed route. Length: {}", length);
+            max_length = std::cmp::max(max_length, length);
+        }
+    }
+
+    max_length
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Route {
+    current: Position,
+    visited: HashSet<Position>,
+    completed: bool,
+    dead_end: bool,
+}
+
+impl Route {
+    fn new(start: Position) -> Self {
+        Route {
+            current: start,
+            visited: HashSet::new(),
+            completed: false,
+            dead_end: false,
+        }
+    }
+
+    fn get_next_positions(&self, map: &Grid<char>) -> Vec<Position> {
+        // A slope can only be taken if the tile it slides onto is still free.
+        let can_slide = |pos: Position, dir: Direction| {
+            pos.step(dir)
+                .is_none_or(|next| !self.visited.contains(&next))
+        };
+
+        let mut next_positions = Vec::new();
+        for pos in map.neighbours(self.current) {
+            let tile = map[pos];
+
+            if self.visited.contains(&pos) {
+                continue;
+            }
+
+            match tile {
+                '.' => {
+                    next_positions.push(pos);
+                }
+                '>' if can_slide(pos, Direction::Right) => {
+                    next_positions.push(pos);
+                }
+                '<' if can_slide(pos, Direction::Left) => {
+                    next_positions.push(pos);
+                }
+                '^' if can_slide(pos, Direction::Up) => {
+                    next_positions.push(pos);
+                }
+                'v' if can_slide(pos, Direction::Down) => {
+                    next_positions.push(pos);
+                }
+
+                _ => {}
+            }
+        }
+        next_positions
+    }
+}
+
+/// The only open tiles in the top and bottom rows.
+fn start_and_goal(map: &Grid<char>) -> (Position, Position) {
+    let open = |row: usize| map.row(row).iter().position(|c| *c == '.').unwrap();
+
+    let last_row = map.height() - 1;
+    (
+        Position::new(0, open(0)),
+        Position::new(last_row, open(last_row)),
+    )
+}
@@ -0,0 +1,199 @@
+//! Day 24: Never Tell Me The Odds, paths of hailstones crossing.
+
+use std::ops::Div;
+
+use common::{
+    parse::{self, ParseError},
+    Solver,
+};
+
+/// The day as a [`Solver`] for the runner.
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 24;
+    type Data = Vec<Line<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Self::Data) -> Option<String> {
+        Some(part_one(data, &(200000000000000, 400000000000000)).to_string())
+    }
+}
+
+#[test]
+fn test() {
+    let example = parse(include_str!("example")).unwrap();
+    let range: (i64, i64) = (7, 27);
+
+    let count = part_one(&example, &range);
+    assert_eq!(count, 2);
+
+    let input = parse(include_str!("input")).unwrap();
+    let range: (i64, i64) = (200000000000000, 400000000000000);
+
+    let count = part_one(&input, &range);
+    assert_eq!(count, 12343);
+}
+
+#[test]
+fn test_read_error() {
+    let error = parse("19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected three numbers, `@` and three numbers, found `18, 19, 22 @ -1, -1`"
+    );
+
+    let error = parse("19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, +-2\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 22));
+}
+
+/// Pairs of hailstones whose paths cross in the future, ignoring `z`, with both coordinates within `range`.
+pub fn part_one(data: &[Line<i64>], range: &(i64, i64)) -> usize {
+    let pairs = build_pairs(data);
+
+    let result = pairs
+        .iter()
+        .map(|pair| test_intersection(pair, range))
+        .collect::<Vec<_>>();
+
+    let count = result.iter().filter(|&&x| x).count();
+    count
+}
+
+fn test_intersection(pair: &(&Line<i64>, &Line<i64>), range: &(i64, i64)) -> bool {
+    let intersection_point = pair.0.intersection_2d(pair.1);
+
+    // is in range?
+    if intersection_point.x < range.0 as f64
+        || intersection_point.y < range.0 as f64
+        || intersection_point.x > range.1 as f64
+        || intersection_point.y > range.1 as f64
+    {
+        return false;
+    }
+
+    // is forwards for any of the two lines?
+    let sign_a = pair.0.dir.x.signum() as f64;
+    let diff_a = intersection_point.x - pair.0.point.x as f64;
+    if sign_a * diff_a < 0f64 {
+        return false;
+    }
+
+    let sign_b = pair.1.dir.x.signum() as f64;
+    let diff_b = intersection_point.x - pair.1.point.x as f64;
+    if sign_b * diff_b < 0f64 {
+        return false;
+    }
+
+    true
+}
+
+/// Every unordered pair of distinct elements, in order.
+pub fn build_pairs<T>(data: &[T]) -> Vec<(&T, &T)> {
+    let mut pairs = Vec::new();
+
+    for i in 0..data.len() {
+        let a = data.get(i);
+        for j in i + 1..data.len() {
+            let b = data.get(j);
+            pairs.push((a.unwrap(), b.unwrap()));
+        }
+    }
+
+    pairs
+}
+
+#[test]
+fn test_pairs() {
+    let data = vec![1, 2, 3];
+
+    let pairs = build_pairs(&data);
+    assert_eq!(pairs, vec![(&1, &2), (&1, &3), (&2, &3)]);
+
+    assert!(build_pairs::<i32>(&[]).is_empty());
+}
+
+/// A hailstone like `19, 13, 30 @ -2,  1, -2`.
+fn parse_line(line: &str) -> Result<Line<i64>, ParseError> {
+    let data = line
+        .split('@')
+        .flat_map(|part| part.split(','))
+        .map(|n| parse::number::<i64>(line, n.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match data[..] {
+        [px, py, pz, vx, vy, vz] => Ok(Line {
+            point: Point3 {
+                x: px,
+                y: py,
+                z: pz,
+            },
+            dir: Vec3 {
+                x: vx,
+                y: vy,
+                z: vz,
+            },
+        }),
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "three numbers, `@` and three numbers",
+        )),
+    }
+}
+
+/// A hailstone per line.
+pub fn parse(input: &str) -> Result<Vec<Line<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_line(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// A hailstone: where it is and how far it moves every nanosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<T: Div> {
+    pub point: Point3<T>,
+    pub dir: Vec3<T>,
+}
+
+impl Line<i64> {
+    fn intersection_2d(&self, pair: &Line<i64>) -> Point2<f64> {
+        let l1 = self; // y = ax + c
+        let l2 = pair; // y = bx + d
+
+        let a = l1.dir.y as f64 / l1.dir.x as f64;
+        let c = l1.point.y as f64 - a * l1.point.x as f64;
+
+        let b = l2.dir.y as f64 / l2.dir.x as f64;
+        let d = l2.point.y as f64 - b * l2.point.x as f64;
+
+        let x = (d - c) / (a - b);
+        let y = a * (d - c) / (a - b) + c;
+
+        Point2 { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[derive(Debug)]
+struct Point2<T> {
+    x: T,
+    y: T,
+}
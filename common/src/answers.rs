@@ -0,0 +1,74 @@
+use crate::{parse::ParseError, Part};
+
+/// A known correct answer, from a day's `answers` file.
+///
+/// Each line of the file is `<input file> <part> <answer>`, e.g. `example 2 467835`, where the input file is next to
+/// the answers file and the part is `1` or `2`. Empty lines and lines starting with `#` are skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+pub fn read_answers(text: &str) -> Result<Vec<Answer>, ParseError> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| read_answer(line).map_err(|e| e.within(text, line)))
+        .collect()
+}
+
+fn read_answer(line: &str) -> Result<Answer, ParseError> {
+    let mut words = line.split_whitespace();
+    let (Some(input), Some(part), Some(answer), None) =
+        (words.next(), words.next(), words.next(), words.next())
+    else {
+        return Err(ParseError::new(
+            line,
+            line,
+            "`<input file> <part> <answer>`",
+        ));
+    };
+
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Err(ParseError::new(line, part, "`1` or `2`")),
+    };
+
+    Ok(Answer {
+        input: input.to_string(),
+        part,
+        answer: answer.to_string(),
+    })
+}
+
+/// The expected answer to `part` on `input`, if it's known.
+pub fn expected<'a>(answers: &'a [Answer], input: &str, part: Part) -> Option<&'a str> {
+    answers
+        .iter()
+        .find(|a| a.input == input && a.part == part)
+        .map(|a| a.answer.as_str())
+}
+
+#[test]
+fn test_read_answers() {
+    let text = "# example first\nexample 1 4361\n\ninput 2 84289137\n";
+    let answers = read_answers(text).unwrap();
+    assert_eq!(
+        answers[1],
+        Answer {
+            input: "input".to_string(),
+            part: Part::Two,
+            answer: "84289137".to_string(),
+        }
+    );
+    assert_eq!(expected(&answers, "example", Part::One), Some("4361"));
+    assert_eq!(expected(&answers, "example", Part::Two), None);
+
+    let error = read_answers("example 1 4361\ninput 3 7").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 7));
+
+    let error = read_answers("input 1").unwrap_err();
+    assert_eq!(error.expected, "`<input file> <part> <answer>`");
+}
@@ -0,0 +1,104 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind, Read},
+    path::PathBuf,
+};
+
+/// Where a puzzle input is read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// Command line convention: `-` is standard input, anything else a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let text = match self {
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map(|_| text)
+            }
+            Input::File(path) => fs::read_to_string(path),
+        };
+
+        text.map_err(|error| InputError {
+            input: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Stdin => f.write_str("standard input"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The puzzle input couldn't be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub input: Input,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.error.kind() {
+            ErrorKind::NotFound => write!(f, "Input file {} doesn't exist", self.input),
+            ErrorKind::PermissionDenied => write!(f, "Not allowed to read {}", self.input),
+            ErrorKind::InvalidData => write!(f, "Input in {} isn't UTF-8 text", self.input),
+            _ => write!(f, "Can't read {}: {}", self.input, self.error),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[test]
+fn test_from_arg() {
+    assert_eq!(Input::from_arg("-"), Input::Stdin);
+    assert_eq!(
+        Input::from_arg("aoc_05/src/input"),
+        Input::File(PathBuf::from("aoc_05/src/input"))
+    );
+}
+
+#[test]
+fn test_read() {
+    let path = std::env::temp_dir().join("common_test_read_input");
+    fs::write(&path, "Time: 7 15 30\n").unwrap();
+
+    let text = Input::File(path.clone()).read().unwrap();
+    assert_eq!(text, "Time: 7 15 30\n");
+
+    fs::write(&path, [0xff, 0xfe]).unwrap();
+    let error = Input::File(path.clone()).read().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!("Input in {} isn't UTF-8 text", path.display())
+    );
+
+    fs::remove_file(&path).unwrap();
+    let error = Input::File(path.clone()).read().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!("Input file {} doesn't exist", path.display())
+    );
+}
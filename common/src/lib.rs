@@ -0,0 +1,146 @@
+use std::{fmt::Display, path::Path, process::ExitCode};
+
+use env_logger::Env;
+use input::Input;
+use parse::ParseError;
+
+pub mod answers;
+pub mod input;
+pub mod parse;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("one"),
+            Part::Two => f.write_str("two"),
+        }
+    }
+}
+
+/// A day's puzzle: how to read its input and how to answer each part.
+pub trait Solver {
+    /// Day of December the puzzle was published on.
+    const DAY: u8;
+
+    /// Whatever both parts need from the input, read once.
+    type Data;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError>;
+
+    /// `None` means the part hasn't been solved yet.
+    fn part_one(data: &Self::Data) -> Option<String>;
+
+    /// `None` means the part hasn't been solved yet.
+    fn part_two(_data: &Self::Data) -> Option<String> {
+        None
+    }
+}
+
+/// Parse the input and answer a single part of the puzzle.
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<Option<String>, ParseError> {
+    let data = S::parse(input)?;
+
+    let answer = match part {
+        Part::One => S::part_one(&data),
+        Part::Two => S::part_two(&data),
+    };
+
+    Ok(answer)
+}
+
+/// The line every binary prints for an answer, e.g. `Day 05, part two: 46`.
+pub fn format_answer(day: u8, part: Part, answer: Option<&str>) -> String {
+    format!(
+        "Day {:02}, part {}: {}",
+        day,
+        part,
+        answer.unwrap_or("unsolved")
+    )
+}
+
+/// Send the solvers' log messages to stderr, nothing is logged unless asked for.
+///
+/// `verbose` turns on debug messages of every day. `RUST_LOG` takes precedence and can pick days and levels, e.g.
+/// `RUST_LOG=aoc_20=trace`.
+pub fn init_logging(verbose: bool) {
+    let default_filter = if verbose { "debug" } else { "off" };
+
+    env_logger::Builder::from_env(Env::default().default_filter_or(default_filter))
+        .format_timestamp(None)
+        .init();
+}
+
+/// Whether `arg` is the command line flag turning on logging.
+pub fn is_verbose_flag(arg: &str) -> bool {
+    arg == "--verbose" || arg == "-v"
+}
+
+/// Entry point of the per-day binaries: print the answers to both parts.
+///
+/// The input is the path given as the only argument, `-` for stdin, or `default_input` without arguments.
+/// `--verbose` can be given anywhere, see [`init_logging`].
+pub fn run<S: Solver>(default_input: &Path) -> ExitCode {
+    let (verbose, args): (Vec<_>, Vec<_>) = std::env::args()
+        .skip(1)
+        .partition(|arg| is_verbose_flag(arg));
+    let input = match &args[..] {
+        [] => Input::File(default_input.to_path_buf()),
+        [arg] => Input::from_arg(arg),
+        _ => {
+            eprintln!(
+                "Usage: aoc_{:02} [--verbose] [input file, - for stdin]",
+                S::DAY
+            );
+            return ExitCode::from(2);
+        }
+    };
+
+    init_logging(!verbose.is_empty());
+
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let data = match S::parse(&text) {
+        Ok(data) => data,
+        Err(error) => {
+            eprintln!("Can't parse {}, {}", input, error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let one = S::part_one(&data);
+    println!("{}", format_answer(S::DAY, Part::One, one.as_deref()));
+
+    let two = S::part_two(&data);
+    println!("{}", format_answer(S::DAY, Part::Two, two.as_deref()));
+
+    ExitCode::SUCCESS
+}
+
+#[test]
+fn test_format_answer() {
+    assert_eq!(
+        format_answer(5, Part::Two, Some("46")),
+        "Day 05, part two: 46"
+    );
+    assert_eq!(
+        format_answer(12, Part::One, None),
+        "Day 12, part one: unsolved"
+    );
+}
@@ -0,0 +1,164 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Malformed puzzle input, with where it went wrong and what should have been there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Starts at 1.
+    pub line: usize,
+    /// Starts at 1, counted in characters.
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// `found` is the offending part of `text`, the error is located relative to the start of `text`.
+    ///
+    /// Slices of `text` are located exactly, anything else at its first occurrence in `text`.
+    pub fn new(text: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(text, found)
+            .or_else(|| text.find(found))
+            .unwrap_or(0);
+        let (line, column) = line_and_column(text, offset);
+
+        ParseError {
+            line,
+            column,
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Relocate an error made relative to `inner` so it is relative to `outer`, which `inner` is a slice of.
+    ///
+    /// Parsers use it to hand errors of their pieces, like a line, back relative to the whole input.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let Some(offset) = offset_in(outer, inner) else {
+            return self;
+        };
+        let (line, column) = line_and_column(outer, offset);
+
+        ParseError {
+            column: if self.line == 1 {
+                self.column + column - 1
+            } else {
+                self.column
+            },
+            line: self.line + line - 1,
+            ..self
+        }
+    }
+}
+
+/// Byte offset of `inner` in `outer` if it's a slice of it.
+fn offset_in(outer: &str, inner: &str) -> Option<usize> {
+    let start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+
+    if inner_start >= start && inner_start + inner.len() <= start + outer.len() {
+        Some(inner_start - start)
+    } else {
+        None
+    }
+}
+
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.found.is_empty() {
+            f.write_str("found nothing")
+        } else {
+            write!(f, "found `{}`", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `found`, a part of `text`, as a number.
+pub fn number<T: FromStr>(text: &str, found: &str) -> Result<T, ParseError> {
+    found
+        .parse()
+        .map_err(|_| ParseError::new(text, found, "a number"))
+}
+
+/// Every whitespace separated word of `found`, a part of `text`, as a number.
+pub fn numbers<T: FromStr>(text: &str, found: &str) -> Result<Vec<T>, ParseError> {
+    found
+        .split_whitespace()
+        .map(|word| number(text, word))
+        .collect()
+}
+
+/// [`str::split_once`], failing when `found`, a part of `text`, doesn't contain `delimiter`.
+pub fn split_once<'a>(
+    text: &str,
+    found: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    found
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, found, format!("`{}`", delimiter.trim())))
+}
+
+/// [`str::strip_prefix`], failing when `found`, a part of `text`, doesn't start with `prefix`.
+pub fn strip_prefix<'a>(text: &str, found: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    found
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(text, found, format!("`{}`", prefix.trim())))
+}
+
+#[test]
+fn test_location() {
+    let input = "Time: 7 15 30\nDistance: 9 4O 200\n";
+    let line = input.lines().nth(1).unwrap();
+
+    let error = numbers::<u32>(line, line.strip_prefix("Distance:").unwrap()).unwrap_err();
+    assert_eq!(
+        error,
+        ParseError {
+            line: 1,
+            column: 13,
+            found: "4O".to_string(),
+            expected: "a number".to_string(),
+        }
+    );
+
+    let error = error.within(input, line);
+    assert_eq!((error.line, error.column), (2, 13));
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 13: expected a number, found `4O`"
+    );
+
+    let error = ParseError::new(input, "", "`Time:`");
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1: expected `Time:`, found nothing"
+    );
+}
+
+#[test]
+fn test_split() {
+    let line = "seeds: 79 14";
+    assert_eq!(split_once(line, line, ": "), Ok(("seeds", "79 14")));
+    assert_eq!(strip_prefix(line, line, "seeds: "), Ok("79 14"));
+
+    let error = split_once(line, "79 14", ",").unwrap_err();
+    assert_eq!(error.column, 8);
+    assert_eq!(error.expected, "`,`");
+}
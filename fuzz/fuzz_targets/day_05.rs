@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solver;
+use libfuzzer_sys::fuzz_target;
+
+// Malformed input has to come back as a parse error, never as a panic.
+fuzz_target!(|input: &str| {
+    let _ = aoc_05::Solution::parse(input);
+});
@@ -0,0 +1,21 @@
+#!/bin/sh
+# Seed every day's fuzz corpus with its example files, or its puzzle input when it has none.
+# Run from anywhere, then e.g. `cargo +nightly fuzz run day_05` in this directory.
+set -e
+
+fuzz=$(dirname "$0")
+for src in "$fuzz"/../aoc_*/src; do
+    day=$(basename "$(dirname "$src")" | sed 's/^aoc_/day_/')
+    corpus="$fuzz/corpus/$day"
+    mkdir -p "$corpus"
+
+    found=false
+    for example in "$src"/example*; do
+        [ -f "$example" ] || continue
+        cp "$example" "$corpus/"
+        found=true
+    done
+    if [ "$found" = false ] && [ -f "$src/input" ]; then
+        cp "$src/input" "$corpus/"
+    fi
+done
@@ -0,0 +1,417 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::parse::ParseError;
+
+/// A cell of a grid, row 0 is the top line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+
+    /// The next position in `dir`, `None` when that would go above or left of the grid.
+    ///
+    /// Only the lower bound is known here, use [`Grid::step`] to also stay inside the grid.
+    pub fn step(&self, dir: Direction) -> Option<Position> {
+        let (row, col) = match dir {
+            Direction::Up => (self.row.checked_sub(1)?, self.col),
+            Direction::Down => (self.row + 1, self.col),
+            Direction::Left => (self.row, self.col.checked_sub(1)?),
+            Direction::Right => (self.row, self.col + 1),
+        };
+
+        Some(Position { row, col })
+    }
+
+    /// Number of steps between the two positions when moving only up, down, left and right.
+    pub fn manhattan_distance(&self, other: &Position) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Clockwise, starting at the top.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().opposite()
+    }
+}
+
+/// A rectangular map of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if the rows aren't all as long as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "Row {} isn't as long as the first one", i);
+            cells.extend(row);
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Every line of the input is a row, every character a cell turned into `T` by `f`.
+    ///
+    /// Fails where `f` gives `None`, `expected` describes the characters it accepts, and on rows of different lengths.
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+        for line in input.lines() {
+            let mut row = Vec::new();
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::new(line, &line[i..i + c.len_utf8()], expected).within(input, line)
+                })?;
+                row.push(cell);
+            }
+
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    let expected = format!("a row of {} cells", first);
+                    return Err(ParseError::new(line, line, expected).within(input, line));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(pos.row * self.width + pos.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(pos.row * self.width + pos.col)
+        } else {
+            None
+        }
+    }
+
+    /// The next position in `dir`, `None` when that would leave the grid.
+    pub fn step(&self, pos: Position, dir: Direction) -> Option<Position> {
+        pos.step(dir).filter(|next| self.contains(*next))
+    }
+
+    /// The up to four positions sharing an edge with `pos`.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to eight positions sharing an edge or a corner with `pos`.
+    pub fn neighbours_diagonal(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        (-1..=1)
+            .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dr, dc)| {
+                let row = pos.row.checked_add_signed(dr)?;
+                let col = pos.col.checked_add_signed(dc)?;
+                Some(Position { row, col })
+            })
+            .filter(|next| self.contains(*next))
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position { row, col }))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Zero sized chunks aren't allowed, an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "Column {} is outside of the grid", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Positions of every cell equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Rows become columns, the grid is mirrored along its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Quarter turn clockwise, the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).rev().cloned())
+            .collect();
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Quarter turn counter-clockwise, the right column becomes the top row.
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Any character is a cell, only the rows have to be the same length.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, "any character", Some)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of the grid", pos),
+        }
+    }
+}
+
+/// One line per row, the way the grid was read.
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse() {
+    let grid = Grid::parse("#.S\n..#\n").unwrap();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid[Position::new(1, 2)], '#');
+    assert_eq!(grid.get(Position::new(2, 0)), None);
+    assert_eq!(grid.get(Position::new(0, 3)), None);
+    assert_eq!(grid.find(&'S'), Some(Position::new(0, 2)));
+    assert_eq!(grid.find_all(&'#').count(), 2);
+    assert_eq!(grid.to_string(), "#.S\n..#\n");
+
+    let digits = Grid::parse_with("12\n34", "a digit", |c| c.to_digit(10)).unwrap();
+    assert_eq!(digits.row(1), [3, 4]);
+    assert_eq!(digits.column(1).collect::<Vec<_>>(), [&2, &4]);
+
+    let empty = Grid::parse("").unwrap();
+    assert_eq!(empty.rows().count(), 0);
+    assert_eq!(empty.positions().count(), 0);
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Grid::parse("...\n..\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.expected, "a row of 3 cells");
+
+    let error = Grid::parse_with("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.found, "x");
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 3, '.');
+
+    let corner = grid.neighbours(Position::new(0, 0)).collect::<Vec<_>>();
+    assert_eq!(corner, [Position::new(0, 1), Position::new(1, 0)]);
+
+    let centre = grid.neighbours(Position::new(1, 1)).count();
+    assert_eq!(centre, 4);
+
+    let corner = grid.neighbours_diagonal(Position::new(2, 2)).count();
+    assert_eq!(corner, 3);
+    let edge = grid.neighbours_diagonal(Position::new(0, 1)).count();
+    assert_eq!(edge, 5);
+    let centre = grid.neighbours_diagonal(Position::new(1, 1)).count();
+    assert_eq!(centre, 8);
+
+    assert_eq!(grid.step(Position::new(2, 1), Direction::Down), None);
+    assert_eq!(
+        grid.step(Position::new(2, 1), Direction::Up),
+        Some(Position::new(1, 1))
+    );
+}
+
+#[test]
+fn test_rotate() {
+    let grid = Grid::parse("ab\ncd\nef").unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+    assert_eq!(grid.rotate_counter_clockwise().to_string(), "bdf\nace\n");
+
+    let turned = (0..4).fold(grid.clone(), |g, _| g.rotate_clockwise());
+    assert_eq!(turned, grid);
+    assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+}
+
+#[test]
+fn test_direction() {
+    for dir in Direction::ALL {
+        assert_eq!(dir.opposite().opposite(), dir);
+        assert_eq!(dir.turn_left().turn_right(), dir);
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+}
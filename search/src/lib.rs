@@ -0,0 +1,150 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A cheapest way to a goal: its cost and every state along it, from the start to the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Cheapest path from `start` to any state `is_goal` accepts, `None` when no goal can be reached.
+///
+/// `successors` gives the states a state leads to and the cost of each move, costs can't be negative and
+/// `C::default()` is zero. `heuristic` estimates the cost left to a goal and must never overestimate it, or the path
+/// found might not be the cheapest.
+pub fn a_star<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are numbered as they're found, `costs[i]` is the cheapest known way to state `i` and which state it
+    // came from.
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut costs = vec![(C::default(), None)];
+
+    let mut open = BinaryHeap::from([Reverse((heuristic(&states[0]), C::default(), 0))]);
+    while let Some(Reverse((_, cost, id))) = open.pop() {
+        if cost > costs[id].0 {
+            // A cheaper way to the state was found after this one was queued.
+            continue;
+        }
+
+        let state = states[id].clone();
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&states, &costs, id),
+            });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let next_id = match ids.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_id = *entry.get();
+                    if costs[next_id].0 <= next_cost {
+                        continue;
+                    }
+                    costs[next_id] = (next_cost, Some(id));
+                    next_id
+                }
+                Entry::Vacant(entry) => {
+                    let next_id = states.len();
+                    states.push(entry.key().clone());
+                    costs.push((next_cost, Some(id)));
+                    entry.insert(next_id);
+                    next_id
+                }
+            };
+
+            let estimate = next_cost + heuristic(&states[next_id]);
+            open.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+/// [`a_star`] without a heuristic.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(start, successors, |_| C::default(), is_goal)
+}
+
+fn reconstruct<S: Clone, C>(states: &[S], costs: &[(C, Option<usize>)], goal: usize) -> Vec<S> {
+    let mut path = vec![states[goal].clone()];
+    let mut id = goal;
+    while let Some(previous) = costs[id].1 {
+        path.push(states[previous].clone());
+        id = previous;
+    }
+
+    path.reverse();
+    path
+}
+
+#[test]
+fn test_dijkstra() {
+    // 0 -> 1 -> 3 costs 1 + 5, 0 -> 2 -> 3 costs 2 + 1.
+    let edges = |n: &u32| match n {
+        0 => vec![(1, 1), (2, 2)],
+        1 => vec![(3, 5)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+
+    let path = dijkstra(0, edges, |&n| n == 3).unwrap();
+    assert_eq!(
+        path,
+        Path {
+            cost: 3,
+            states: vec![0, 2, 3]
+        }
+    );
+
+    assert_eq!(dijkstra(0, edges, |&n| n == 4), None);
+    assert_eq!(dijkstra(3, edges, |&n| n == 3).unwrap().states, vec![3]);
+}
+
+#[test]
+fn test_a_star() {
+    // Walk a 5 by 5 grid to the far corner, the diagonal through the middle is expensive.
+    let goal = (4_i32, 4_i32);
+    let successors = |&(x, y): &(i32, i32)| {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .map(|(x, y)| ((x, y), if x == y { 10 } else { 1 }))
+    };
+    let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+
+    let path = a_star((0, 0), successors, manhattan, |&p| p == goal).unwrap();
+    assert_eq!(path.cost, 17);
+    assert_eq!(path.states.len(), 9);
+    assert_eq!(path.states.first(), Some(&(0, 0)));
+    assert_eq!(path.states.last(), Some(&goal));
+
+    let dijkstra = dijkstra((0, 0), successors, |&p| p == goal).unwrap();
+    assert_eq!(dijkstra.cost, path.cost);
+}